@@ -1,34 +1,42 @@
 #![allow(clippy::manual_unwrap_or_default)] // darling macros
 
+use std::collections::HashSet;
+
 use darling::{
     FromDeriveInput, FromField, FromMeta, FromVariant,
-    ast::{Data, Fields},
+    ast::{Data, Fields, NestedMeta},
     util::{Flag, Override, SpannedValue},
 };
+use heck::{ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro_error2::{abort_call_site, emit_error};
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::spanned::Spanned;
+use quote::{ToTokens, format_ident, quote};
+use syn::{ext::IdentExt, spanned::Spanned};
 
-use crate::type_path_ext::TypePathWrapper;
+use crate::type_path_ext::{DeriveTy, TypePathWrapper};
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(mapper), supports(struct_named, struct_newtype, struct_tuple, enum_any))]
+#[darling(
+    attributes(mapper),
+    supports(struct_named, struct_newtype, struct_tuple, struct_unit, enum_any)
+)]
 pub(super) struct MapperOpts {
     /// The identifier of the passed-in type
     pub(super) ident: syn::Ident,
+    /// The visibility of the passed-in type
+    pub(super) vis: syn::Visibility,
     /// The generics of the passed-in type
     pub(super) generics: syn::Generics,
     /// The body of the passed-in type
     pub(super) data: Data<VariantReceiver, FieldReceiver>,
 
     #[darling(default, multiple, rename = "derive")]
-    items: Vec<ItemInput>,
+    items: Vec<DeriveItemInput>,
 
     /// Path of the struct or enum
     #[darling(default, rename = "ty")]
-    path: Option<SpannedValue<TypePathWrapper>>,
+    path: Option<SpannedValue<DeriveTy>>,
     /// Whether to derive [From] the type to self
     #[darling(default)]
     from: Option<SpannedValue<Override<DeriveInput>>>,
@@ -47,13 +55,144 @@ pub(super) struct MapperOpts {
     /// Whether to ignore all extra fields/variants of the other type
     #[darling(default)]
     ignore_extra: SpannedValue<Flag>,
+    /// Extra fields/variants of the other type to ignore
+    #[darling(default)]
+    ignore: Option<SpannedValue<IgnoreList>>,
+    /// Whether to populate the unmapped fields of either side with their default value
+    #[darling(default)]
+    fill_default: SpannedValue<Flag>,
+    /// Type-directed conversions for every matching field
+    #[darling(default, multiple)]
+    convert: Vec<ConvertInput>,
+    /// Intermediate type to compose the conversions through
+    #[darling(default)]
+    via: Option<SpannedValue<TypePathWrapper>>,
+    /// Variant of the other enum self is mapped from or into
+    #[darling(default)]
+    variant: Option<SpannedValue<syn::Ident>>,
+    /// Value of self when deriving from any other variant of the other enum
+    #[darling(default)]
+    default: Option<SpannedValue<Override<DefaultInput>>>,
+    /// Rules to compute the name of every field/variant on the other type
+    #[darling(flatten)]
+    rename_rules: RenameRulesInput,
+
+    /// Previous versions of the type to upgrade from
+    #[darling(default)]
+    pub(super) upgrade_from: Option<SpannedValue<UpgradeInput>>,
+}
+
+/// A `derive` item, mapping to either another type, strings or integers
+#[derive(Debug, Clone)]
+enum DeriveItemInput {
+    Type(Box<ItemInput>),
+    Str(SpannedValue<StrInput>),
+    Int(SpannedValue<IntInput>),
+}
+
+impl FromMeta for DeriveItemInput {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        let syn::Meta::List(list) = item else {
+            return ItemInput::from_meta(item).map(|i| DeriveItemInput::Type(Box::new(i)));
+        };
+        let items = NestedMeta::parse_meta_list(list.tokens.clone())?;
+        let is_word =
+            |i: &NestedMeta, word: &str| matches!(i, NestedMeta::Meta(syn::Meta::Path(p)) if p.is_ident(word));
+        if items.iter().any(|i| is_word(i, "str")) {
+            let rest = items.into_iter().filter(|i| !is_word(i, "str")).collect::<Vec<_>>();
+            let input = StrInput::from_list(&rest)?;
+            Ok(DeriveItemInput::Str(SpannedValue::new(input, item.span())))
+        } else if items.iter().any(|i| is_word(i, "int")) {
+            let rest = items.into_iter().filter(|i| !is_word(i, "int")).collect::<Vec<_>>();
+            let input = IntInput::from_list(&rest)?;
+            Ok(DeriveItemInput::Int(SpannedValue::new(input, item.span())))
+        } else {
+            ItemInput::from_list(&items).map(|i| DeriveItemInput::Type(Box::new(i)))
+        }
+    }
+}
+
+#[derive(Debug, FromMeta, Clone)]
+pub(super) struct StrInput {
+    /// The case convention of the variant names (defaults to the variant identifiers as they are)
+    #[darling(default)]
+    pub(super) case: Option<SpannedValue<CaseInput>>,
+    /// Whether to parse the strings ignoring their ASCII case
+    #[darling(default)]
+    pub(super) case_insensitive: Flag,
+}
+
+#[derive(Debug, FromMeta, Clone)]
+pub(super) struct IntInput {
+    /// The integer type (defaults to `i32`)
+    #[darling(default)]
+    ty: Option<TypePathWrapper>,
+    /// The variant unknown values are mapped into, instead of failing
+    #[darling(default)]
+    pub(super) fallback: Option<SpannedValue<syn::Ident>>,
+}
+
+impl IntInput {
+    /// The integer type to map from and into
+    pub(super) fn ty(&self) -> syn::TypePath {
+        self.ty.as_deref().cloned().unwrap_or_else(|| syn::parse_quote!(i32))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum CaseInput {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl CaseInput {
+    /// Converts the given identifier to this case
+    pub(super) fn convert(&self, ident: &str) -> String {
+        match self {
+            CaseInput::Lower => ident.to_lowercase(),
+            CaseInput::Upper => ident.to_uppercase(),
+            CaseInput::Pascal => ident.to_upper_camel_case(),
+            CaseInput::Camel => ident.to_lower_camel_case(),
+            CaseInput::Snake => ident.to_snake_case(),
+            CaseInput::ScreamingSnake => ident.to_shouty_snake_case(),
+            CaseInput::Kebab => ident.to_kebab_case(),
+            CaseInput::ScreamingKebab => ident.to_shouty_kebab_case(),
+        }
+    }
+}
+
+impl FromMeta for CaseInput {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(match value {
+            "lowercase" => CaseInput::Lower,
+            "UPPERCASE" => CaseInput::Upper,
+            "PascalCase" => CaseInput::Pascal,
+            "camelCase" => CaseInput::Camel,
+            "snake_case" => CaseInput::Snake,
+            "SCREAMING_SNAKE_CASE" => CaseInput::ScreamingSnake,
+            "kebab-case" => CaseInput::Kebab,
+            "SCREAMING-KEBAB-CASE" => CaseInput::ScreamingKebab,
+            _ => {
+                return Err(darling::Error::custom(format!(
+                    "Unknown case '{value}', expected one of: lowercase, UPPERCASE, PascalCase, camelCase, \
+                     snake_case, SCREAMING_SNAKE_CASE, kebab-case or SCREAMING-KEBAB-CASE"
+                )));
+            }
+        })
+    }
 }
 
 #[derive(Debug, FromMeta, Clone)]
 pub(super) struct ItemInput {
     /// Path of the struct or enum
     #[darling(rename = "ty")]
-    pub(super) path: SpannedValue<TypePathWrapper>,
+    pub(super) path: SpannedValue<DeriveTy>,
     /// Whether to derive [From] the type to self
     #[darling(default)]
     pub(super) from: Option<SpannedValue<Override<DeriveInput>>>,
@@ -72,6 +211,83 @@ pub(super) struct ItemInput {
     /// Whether to ignore all extra fields/variants of the other type
     #[darling(default)]
     pub(super) ignore_extra: SpannedValue<Flag>,
+    /// Extra fields/variants of the other type to ignore
+    #[darling(default)]
+    pub(super) ignore: Option<SpannedValue<IgnoreList>>,
+    /// Whether to populate the unmapped fields of either side with their default value
+    #[darling(default)]
+    pub(super) fill_default: SpannedValue<Flag>,
+    /// Type-directed conversions for every matching field
+    #[darling(default, multiple)]
+    pub(super) convert: Vec<ConvertInput>,
+    /// Intermediate type to compose the conversions through
+    #[darling(default)]
+    pub(super) via: Option<SpannedValue<TypePathWrapper>>,
+    /// Variant of the other enum self is mapped from or into
+    #[darling(default)]
+    pub(super) variant: Option<SpannedValue<syn::Ident>>,
+    /// Value of self when deriving from any other variant of the other enum
+    #[darling(default)]
+    pub(super) default: Option<SpannedValue<Override<DefaultInput>>>,
+    /// Rules to compute the name of every field/variant on the other type
+    #[darling(flatten)]
+    pub(super) rename_rules: RenameRulesInput,
+}
+
+#[derive(Debug, Default, FromMeta, Clone)]
+pub(super) struct RenameRulesInput {
+    /// The case convention of the names
+    #[darling(default)]
+    rename_all: Option<SpannedValue<CaseInput>>,
+    /// Prefix to remove from the names, before converting them
+    #[darling(default)]
+    strip_prefix: Option<SpannedValue<String>>,
+    /// Prefix to add to the names, after converting them
+    #[darling(default)]
+    add_prefix: Option<SpannedValue<String>>,
+    /// Suffix to add to the names, after converting them
+    #[darling(default)]
+    add_suffix: Option<SpannedValue<String>>,
+}
+
+impl RenameRulesInput {
+    /// The span of the first rule set, if any
+    pub(super) fn span(&self) -> Option<Span> {
+        self.rename_all
+            .as_ref()
+            .map(|r| r.span())
+            .or_else(|| self.strip_prefix.as_ref().map(|r| r.span()))
+            .or_else(|| self.add_prefix.as_ref().map(|r| r.span()))
+            .or_else(|| self.add_suffix.as_ref().map(|r| r.span()))
+    }
+
+    /// Computes the name of the given field or variant on the other type, if there's any rule set
+    pub(super) fn rename(&self, ident: &syn::Ident) -> Option<syn::Ident> {
+        let span = self.span()?;
+        let mut name = ident.unraw().to_string();
+        if let Some(prefix) = self.strip_prefix.as_deref()
+            && let Some(stripped) = name.strip_prefix(prefix.as_str())
+        {
+            name = stripped.to_string();
+        }
+        if let Some(case) = self.rename_all.as_deref() {
+            name = case.convert(&name);
+        }
+        name = format!(
+            "{}{name}{}",
+            self.add_prefix.as_deref().map(String::as_str).unwrap_or_default(),
+            self.add_suffix.as_deref().map(String::as_str).unwrap_or_default()
+        );
+        // Keywords are turned into raw identifiers
+        if let Ok(renamed) = syn::parse_str::<syn::Ident>(&name) {
+            Some(syn::Ident::new(&renamed.to_string(), ident.span()))
+        } else if syn::parse_str::<syn::Ident>(&format!("r#{name}")).is_ok() {
+            Some(syn::Ident::new_raw(&name, ident.span()))
+        } else {
+            emit_error!(span, "The rules compute an invalid name for '{}': '{name}'", ident);
+            None
+        }
+    }
 }
 
 #[derive(Debug, FromVariant, Clone)]
@@ -89,7 +305,7 @@ pub(super) struct VariantReceiver {
 
     /// Path of the struct or enum to derive
     #[darling(default, rename = "ty")]
-    path: Option<SpannedValue<TypePathWrapper>>,
+    path: Option<SpannedValue<DeriveTy>>,
     /// To rename the variant
     #[darling(default)]
     rename: Option<SpannedValue<syn::Ident>>,
@@ -102,6 +318,31 @@ pub(super) struct VariantReceiver {
     /// Whether to ignore all extra fields of the other variant
     #[darling(default)]
     ignore_extra: SpannedValue<Flag>,
+    /// Extra fields of the other variant to ignore
+    #[darling(default)]
+    ignore: Option<SpannedValue<IdentList>>,
+    /// The shape of the fields of the other variant, if different
+    #[darling(default)]
+    shape: Option<SpannedValue<ShapeInput>>,
+    /// Variants of the other enum mapped from, instead of the one with the same (or renamed) name
+    #[darling(default)]
+    from_variants: Option<SpannedValue<IdentList>>,
+    /// Variant of the other enum mapped into, instead of the one with the same (or renamed) name
+    #[darling(default)]
+    into_variant: Option<SpannedValue<syn::Ident>>,
+    /// Predicate on the fields of the other variant telling apart the variants mapped from the same one
+    #[darling(default)]
+    split_by: Option<SpannedValue<syn::Expr>>,
+    /// Additional strings the variant is parsed from, on `str` derives
+    #[darling(default, multiple)]
+    pub(super) alias: Vec<SpannedValue<String>>,
+    /// The integer the variant is mapped to, on `int` derives (defaults to its discriminant)
+    #[darling(default)]
+    pub(super) code: Option<SpannedValue<syn::Expr>>,
+
+    /// The names of the variant on other types, computed from their rename rules
+    #[darling(skip)]
+    renamed: Vec<(DeriveTy, syn::Ident)>,
 }
 macro_field_utils::variant_info!(VariantReceiver, FieldReceiver);
 
@@ -109,7 +350,7 @@ macro_field_utils::variant_info!(VariantReceiver, FieldReceiver);
 struct ItemVariantInput {
     /// Path of the struct or enum to derive
     #[darling(rename = "ty")]
-    path: SpannedValue<TypePathWrapper>,
+    path: SpannedValue<DeriveTy>,
     /// To rename the variant
     #[darling(default)]
     rename: Option<SpannedValue<syn::Ident>>,
@@ -122,6 +363,21 @@ struct ItemVariantInput {
     /// Whether to ignore all extra fields of the other variant
     #[darling(default)]
     ignore_extra: SpannedValue<Flag>,
+    /// Extra fields of the other variant to ignore
+    #[darling(default)]
+    ignore: Option<SpannedValue<IdentList>>,
+    /// The shape of the fields of the other variant, if different
+    #[darling(default)]
+    shape: Option<SpannedValue<ShapeInput>>,
+    /// Variants of the other enum mapped from, instead of the one with the same (or renamed) name
+    #[darling(default)]
+    from_variants: Option<SpannedValue<IdentList>>,
+    /// Variant of the other enum mapped into, instead of the one with the same (or renamed) name
+    #[darling(default)]
+    into_variant: Option<SpannedValue<syn::Ident>>,
+    /// Predicate on the fields of the other variant telling apart the variants mapped from the same one
+    #[darling(default)]
+    split_by: Option<SpannedValue<syn::Expr>>,
 }
 
 #[derive(Debug, FromField, Clone)]
@@ -139,7 +395,7 @@ pub(super) struct FieldReceiver {
 
     /// Path of the struct or enum to derive
     #[darling(default, rename = "ty")]
-    path: Option<SpannedValue<TypePathWrapper>>,
+    path: Option<SpannedValue<DeriveTy>>,
     /// To rename the field
     #[darling(default)]
     rename: Option<SpannedValue<syn::Ident>>,
@@ -149,9 +405,43 @@ pub(super) struct FieldReceiver {
     /// To use another source generic type for mapping
     #[darling(default)]
     other_ty: Option<SpannedValue<syn::Ident>>,
+    /// To flatten the fields of this one into the other type
+    #[darling(default)]
+    flatten: Option<SpannedValue<FlattenInput>>,
+    /// To read the field through a custom getter method of the other type
+    #[darling(default)]
+    getter: Option<SpannedValue<GetterInput>>,
+    /// To set the field through a custom setter method of the builder of the other type
+    #[darling(default)]
+    setter: Option<SpannedValue<syn::Ident>>,
+    /// To map the field from or into the field at this position of the other type
+    #[darling(default)]
+    index: Option<SpannedValue<usize>>,
+    /// To map the unnamed field from or into the field with this name of the other type
+    #[darling(default)]
+    name: Option<SpannedValue<syn::Ident>>,
+    /// To map the field from a nested field of the other type
+    #[darling(default)]
+    from_path: Option<SpannedValue<FromPathInput>>,
+    /// To build the field from many fields of the other type
+    #[darling(default)]
+    combine: Option<SpannedValue<CombineInput>>,
+    /// To split the field into many fields of the other type
+    #[darling(default)]
+    split: Option<SpannedValue<SplitInput>>,
+    /// The source type of the field, when deriving from many types
+    #[darling(default)]
+    source: Option<SpannedValue<TypePathWrapper>>,
+    /// The target types of the field, when deriving into many types
+    #[darling(default)]
+    target: Option<SpannedValue<TypePathList>>,
     /// Mapper hints
     #[darling(flatten)]
     hint: MapperHint,
+
+    /// The names of the field on other types, computed from their rename rules
+    #[darling(skip)]
+    renamed: Vec<(DeriveTy, syn::Ident)>,
 }
 macro_field_utils::field_info!(FieldReceiver);
 
@@ -159,7 +449,7 @@ macro_field_utils::field_info!(FieldReceiver);
 struct ItemFieldInput {
     /// Path of the struct or enum to derive
     #[darling(rename = "ty")]
-    path: SpannedValue<TypePathWrapper>,
+    path: SpannedValue<DeriveTy>,
     /// To rename the field
     #[darling(default)]
     rename: Option<SpannedValue<syn::Ident>>,
@@ -169,6 +459,36 @@ struct ItemFieldInput {
     /// To use another source generic type for mapping
     #[darling(default)]
     other_ty: Option<SpannedValue<syn::Ident>>,
+    /// To flatten the fields of this one into the other type
+    #[darling(default)]
+    flatten: Option<SpannedValue<FlattenInput>>,
+    /// To read the field through a custom getter method of the other type
+    #[darling(default)]
+    getter: Option<SpannedValue<GetterInput>>,
+    /// To set the field through a custom setter method of the builder of the other type
+    #[darling(default)]
+    setter: Option<SpannedValue<syn::Ident>>,
+    /// To map the field from or into the field at this position of the other type
+    #[darling(default)]
+    index: Option<SpannedValue<usize>>,
+    /// To map the unnamed field from or into the field with this name of the other type
+    #[darling(default)]
+    name: Option<SpannedValue<syn::Ident>>,
+    /// To map the field from a nested field of the other type
+    #[darling(default)]
+    from_path: Option<SpannedValue<FromPathInput>>,
+    /// To build the field from many fields of the other type
+    #[darling(default)]
+    combine: Option<SpannedValue<CombineInput>>,
+    /// To split the field into many fields of the other type
+    #[darling(default)]
+    split: Option<SpannedValue<SplitInput>>,
+    /// The source type of the field, when deriving from many types
+    #[darling(default)]
+    source: Option<SpannedValue<TypePathWrapper>>,
+    /// The target types of the field, when deriving into many types
+    #[darling(default)]
+    target: Option<SpannedValue<TypePathList>>,
     /// Mapper hints
     #[darling(flatten)]
     hint: MapperHint,
@@ -185,6 +505,15 @@ pub(super) struct MapperHint {
     /// To use some function to map the values
     #[darling(default)]
     from_with: Option<SpannedValue<syn::Expr>>,
+    /// To use a bidirectional codec module or type to map the values
+    #[darling(default)]
+    codec: Option<SpannedValue<syn::Path>>,
+    /// To use an adapter type to map the values
+    #[darling(default)]
+    via_adapter: Option<SpannedValue<syn::Path>>,
+    /// To map the values through an intermediate type
+    #[darling(default)]
+    via: Option<SpannedValue<ViaInput>>,
     /// Whether the field is an option
     #[darling(default)]
     opt: Option<SpannedValue<Override<Box<MapperHint>>>>,
@@ -197,6 +526,9 @@ pub(super) struct MapperHint {
     /// Whether the field is boxed on both sides
     #[darling(default)]
     boxed: Option<SpannedValue<Override<Box<MapperHint>>>>,
+    /// Whether the field is any container implementing `MapInner` on both sides
+    #[darling(default)]
+    inner: Option<SpannedValue<Override<Box<MapperHint>>>>,
     /// Whether the target field is boxed and the source is not
     #[darling(default, rename = "box")]
     r#box: Option<SpannedValue<Override<Box<MapperHint>>>>,
@@ -205,11 +537,168 @@ pub(super) struct MapperHint {
     unbox: Option<SpannedValue<Override<Box<MapperHint>>>>,
 }
 
+impl MapperHint {
+    /// The spans of every hint set
+    fn spans(&self) -> Vec<Span> {
+        [
+            self.with.as_ref().map(|h| h.span()),
+            self.into_with.as_ref().map(|h| h.span()),
+            self.from_with.as_ref().map(|h| h.span()),
+            self.codec.as_ref().map(|h| h.span()),
+            self.via_adapter.as_ref().map(|h| h.span()),
+            self.via.as_ref().map(|h| h.span()),
+            self.opt.as_ref().map(|h| h.span()),
+            self.iter.as_ref().map(|h| h.span()),
+            self.map.as_ref().map(|h| h.span()),
+            self.boxed.as_ref().map(|h| h.span()),
+            self.inner.as_ref().map(|h| h.span()),
+            self.r#box.as_ref().map(|h| h.span()),
+            self.unbox.as_ref().map(|h| h.span()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
 #[derive(Debug, FromMeta, Clone)]
 pub(super) struct DeriveInput {
     /// Whether the derive has external properties or not (name of the custom function if populated)
     #[darling(default)]
     pub(super) custom: Option<SpannedValue<Override<syn::Ident>>>,
+    /// Whether to read the fields of the other type through getter methods instead of deconstructing it
+    #[darling(default)]
+    pub(super) getters: SpannedValue<Flag>,
+    /// Constructor used to build the other type instead of a struct literal
+    #[darling(default)]
+    pub(super) constructor: Option<SpannedValue<ConstructorInput>>,
+    /// Function returning a builder used to build the other type instead of a struct literal
+    #[darling(default)]
+    pub(super) builder: Option<SpannedValue<syn::Expr>>,
+    /// Name of the builder method building the other type (defaults to `build`)
+    #[darling(default)]
+    pub(super) build: Option<SpannedValue<syn::Ident>>,
+    /// Whether to provide the parameters of the custom function through a staged builder
+    #[darling(default)]
+    pub(super) params_builder: SpannedValue<Flag>,
+}
+
+impl DeriveInput {
+    /// Whether the derive reads the fields of the other type through getter methods
+    pub(super) fn uses_getters(derive: &Override<DeriveInput>) -> bool {
+        derive
+            .as_ref()
+            .explicit()
+            .map(|e| e.getters.is_present())
+            .unwrap_or(false)
+    }
+
+    /// The constructor used to build the other type, if any
+    pub(super) fn constructor(derive: &Override<DeriveInput>) -> Option<&SpannedValue<ConstructorInput>> {
+        derive.as_ref().explicit().and_then(|e| e.constructor.as_ref())
+    }
+
+    /// The builder used to build the other type along with the name of its build method, if any
+    pub(super) fn builder(derive: &Override<DeriveInput>) -> Option<(&syn::Expr, syn::Ident)> {
+        let derive = derive.as_ref().explicit()?;
+        let builder = derive.builder.as_deref()?;
+        let build = derive
+            .build
+            .as_deref()
+            .cloned()
+            .unwrap_or_else(|| format_ident!("build"));
+        Some((builder, build))
+    }
+
+    /// Whether the parameters of the custom function are provided through a staged builder
+    pub(super) fn params_builder(derive: &Override<DeriveInput>) -> bool {
+        derive
+            .as_ref()
+            .explicit()
+            .map(|e| e.params_builder.is_present())
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct ConstructorInput {
+    /// The function to call
+    pub(super) func: syn::Expr,
+    /// The fields of the other type passed as arguments, in order
+    pub(super) args: Vec<syn::Ident>,
+}
+
+impl FromMeta for ConstructorInput {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        if let syn::Lit::Str(s) = value {
+            Self::from_expr(&s.parse().map_err(darling::Error::custom)?)
+        } else {
+            Err(darling::Error::unexpected_lit_type(value))
+        }
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        let syn::Expr::Call(call) = expr else {
+            return Err(
+                darling::Error::custom("Expected a function call, like `Other::new(field_a, field_b)`").with_span(expr),
+            );
+        };
+        let args = call
+            .args
+            .iter()
+            .map(|arg| match arg {
+                syn::Expr::Path(path) if path.qself.is_none() => path
+                    .path
+                    .get_ident()
+                    .cloned()
+                    .ok_or_else(|| darling::Error::custom("Expected a field name").with_span(path)),
+                _ => Err(darling::Error::custom("Expected a field name").with_span(arg)),
+            })
+            .collect::<darling::Result<Vec<_>>>()?;
+        Ok(ConstructorInput {
+            func: (*call.func).clone(),
+            args,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct GetterInput(syn::Ident);
+
+impl GetterInput {
+    /// Whether the getter takes the other type by value
+    pub(super) fn is_by_value(&self) -> bool {
+        self.0.to_string().starts_with("into_")
+    }
+}
+
+impl FromMeta for GetterInput {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        if let syn::Lit::Str(s) = value {
+            Self::from_expr(&s.parse().map_err(darling::Error::custom)?)
+        } else {
+            Err(darling::Error::unexpected_lit_type(value))
+        }
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Call(call) if call.args.is_empty() => Self::from_expr(&call.func),
+            syn::Expr::Path(path) if path.qself.is_none() => path
+                .path
+                .get_ident()
+                .cloned()
+                .map(GetterInput)
+                .ok_or_else(|| darling::Error::custom("Expected a method name").with_span(path)),
+            _ => Err(darling::Error::custom("Expected a method name, like `name()`").with_span(expr)),
+        }
+    }
+}
+
+impl quote::ToTokens for GetterInput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
 }
 
 #[derive(Debug, FromMeta, Clone)]
@@ -222,6 +711,85 @@ pub(super) struct AddInput {
     /// Default value for the field
     #[darling(default)]
     pub(super) default: Option<SpannedValue<Override<DefaultInput>>>,
+    /// Position of the field, when the other variant is a tuple
+    #[darling(default)]
+    pub(super) index: Option<SpannedValue<usize>>,
+    /// The type the field is taken from, when deriving from many types
+    #[darling(default)]
+    pub(super) source: Option<SpannedValue<TypePathWrapper>>,
+    /// The type the field belongs to, when deriving into many types
+    #[darling(default)]
+    pub(super) target: Option<SpannedValue<TypePathWrapper>>,
+}
+
+impl AddInput {
+    /// Index of the part of the other type this field belongs to on the given direction, when deriving many types
+    /// (defaults to the first)
+    pub(super) fn part_for(&self, from: bool, derive_path: &DeriveTy) -> usize {
+        let part = if from { &self.source } else { &self.target };
+        part.as_deref()
+            .and_then(|part| derive_path.parts().iter().position(|p| *p == part.as_ref()))
+            .unwrap_or_default()
+    }
+}
+
+/// The shape of the fields of a variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ShapeInput {
+    /// Unnamed fields, accessed by position
+    Tuple,
+    /// Named fields
+    Named,
+}
+
+impl FromMeta for ShapeInput {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "tuple" => Ok(ShapeInput::Tuple),
+            "named" => Ok(ShapeInput::Named),
+            _ => Err(darling::Error::custom(format!(
+                "Unknown shape '{value}', expected one of: tuple, named"
+            ))),
+        }
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Path(path) if path.path.get_ident().is_some() => {
+                let ident = path.path.get_ident().expect("ident");
+                Self::from_string(&ident.to_string()).map_err(|e| e.with_span(ident))
+            }
+            syn::Expr::Lit(lit) => Self::from_value(&lit.lit),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
+        }
+    }
+}
+
+#[derive(Debug, FromMeta, Clone)]
+pub(super) struct ConvertInput {
+    /// The type of the value to convert
+    pub(super) src: SpannedValue<TypePathWrapper>,
+    /// The type the value is converted into
+    pub(super) dst: SpannedValue<TypePathWrapper>,
+    /// The function used to convert the values
+    pub(super) with: SpannedValue<syn::Expr>,
+    /// Whether the rule only applies on `from` and `try_from` derives
+    #[darling(default)]
+    pub(super) from: SpannedValue<Flag>,
+    /// Whether the rule only applies on `into` and `try_into` derives
+    #[darling(default)]
+    pub(super) into: SpannedValue<Flag>,
+}
+
+impl ConvertInput {
+    /// Whether the rule applies on the given direction
+    pub(super) fn applies_to(&self, from: bool) -> bool {
+        if from {
+            !self.into.is_present()
+        } else {
+            !self.from.is_present()
+        }
+    }
 }
 
 #[derive(Debug, FromMeta, Clone)]
@@ -237,92 +805,569 @@ pub(super) struct SkipInput {
     pub(super) default: Option<SpannedValue<Override<DefaultInput>>>,
 }
 
-impl MapperOpts {
-    /// Retrieve the [ItemInput] of the [MapperOpts]
-    pub(super) fn items(&self) -> Vec<ItemInput> {
-        if !self.items.is_empty() {
-            // If there are multiple derives, the root-level fields are not allowed
-            if let Some(path) = self.path.as_ref() {
-                emit_error!(path.span(), "Illegal attribute when 'derive' is set")
-            }
-            if let Some(from) = self.from.as_ref() {
-                emit_error!(from.span(), "Illegal attribute when 'derive' is set")
-            }
-            if let Some(into) = self.into.as_ref() {
-                emit_error!(into.span(), "Illegal attribute when 'derive' is set")
-            }
-            if let Some(try_from) = self.try_from.as_ref() {
-                emit_error!(try_from.span(), "Illegal attribute when 'derive' is set")
-            }
-            if let Some(try_into) = self.try_into.as_ref() {
-                emit_error!(try_into.span(), "Illegal attribute when 'derive' is set")
-            }
-            if self.ignore_extra.is_present() {
-                emit_error!(self.ignore_extra.span(), "Illegal attribute when 'derive' is set")
-            }
-            if !self.add.is_empty() {
-                for i in &self.add {
-                    emit_error!(i.field.span(), "Illegal attribute when 'derive' is set")
-                }
-            }
-            // Verify there same type is not duplicated
-            let paths = self.items.iter().map(|i| &i.path).collect::<Vec<_>>();
-            for i in 0..paths.len() {
-                for j in 0..paths.len() {
-                    if i != j && paths[i].as_ref() == paths[j].as_ref() {
-                        emit_error!(paths[j].span(), "This type is duplicated")
-                    }
-                }
-            }
-            // Return all of the items
-            self.items.to_vec()
-        } else if let Some(path) = self.path.as_ref() {
-            // If there are a single derive, wrap it on a vec
-            vec![ItemInput {
-                path: path.clone(),
-                from: self.from.clone(),
-                into: self.into.clone(),
-                try_from: self.try_from.clone(),
-                try_into: self.try_into.clone(),
-                ignore_extra: self.ignore_extra,
-                add: self.add.clone(),
-            }]
+#[derive(Debug, Clone)]
+pub(super) struct ViaInput {
+    /// The intermediate type
+    ty: syn::Type,
+    /// Whether the conversions to and from the intermediate type are fallible
+    fallible: bool,
+}
+
+impl FromMeta for ViaInput {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        if let syn::Lit::Str(s) = value {
+            Ok(ViaInput {
+                ty: s.parse().map_err(darling::Error::custom)?,
+                fallible: false,
+            })
         } else {
-            // If there are no derives, abort
-            abort_call_site!("One of 'ty' or 'derive' must be set")
+            Err(darling::Error::unexpected_lit_type(value))
         }
     }
-}
 
-impl ItemInput {
-    /// Validates the input is well formed, emitting errors if not
-    pub(super) fn validate(&self, is_enum: bool) {
-        // At least one kind of derive must be set
-        if self.from.is_none() && self.into.is_none() && self.try_from.is_none() && self.try_into.is_none() {
-            emit_error!(
-                self.path.span(),
-                "One of 'from', 'into', 'try_from' or 'try_into' must be set"
-            );
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Path(path_expr) => Ok(ViaInput {
+                ty: syn::Type::Path(syn::TypePath {
+                    qself: path_expr.qself.clone(),
+                    path: path_expr.path.clone(),
+                }),
+                fallible: false,
+            }),
+            syn::Expr::Lit(lit_expr) => Self::from_value(&lit_expr.lit),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
         }
-        // If there are additional items without a default value (for structs only)
-        let items = self.add.iter().filter(|a| a.default.is_none()).collect::<Vec<_>>();
-        if !items.is_empty() && !is_enum {
-            // into and try_into must have the custom flag if enabled and ty must be set
-            let mut has_into = false;
-            let mut missing_custom = false;
-            if let Some(into) = self.into.as_deref() {
-                has_into = true;
-                if into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
-                    missing_custom = true;
-                }
-            }
-            if let Some(try_into) = self.try_into.as_deref() {
-                has_into = true;
-                if try_into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
-                    missing_custom = true;
-                }
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut items = items.iter();
+        // The first item is the intermediate type
+        let mut via = match items.next() {
+            Some(NestedMeta::Meta(syn::Meta::Path(path))) => ViaInput {
+                ty: syn::Type::Path(syn::TypePath {
+                    qself: None,
+                    path: path.clone(),
+                }),
+                fallible: false,
+            },
+            Some(NestedMeta::Lit(lit)) => Self::from_value(lit)?,
+            Some(item) => return Err(darling::Error::custom("Expected the intermediate type").with_span(item)),
+            None => return Err(darling::Error::too_few_items(1)),
+        };
+        // Followed by the optional flags
+        for item in items {
+            match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("try") => via.fallible = true,
+                _ => return Err(darling::Error::custom("Expected 'try'").with_span(item)),
             }
-            if missing_custom || has_into {
+        }
+        Ok(via)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct UpgradeInput {
+    /// The previous versions, from the most recent to the oldest one
+    pub(super) versions: Vec<SpannedValue<TypePathWrapper>>,
+    /// Whether the conversions between versions are fallible
+    pub(super) fallible: bool,
+    /// Name of the versioned enum wrapper to generate
+    pub(super) wrapper: Option<syn::Ident>,
+}
+
+#[derive(Debug, FromMeta, Clone)]
+pub(super) struct CombineInput {
+    /// The fields of the other type
+    fields: IdentList,
+    /// The function receiving every field to build this one
+    with: syn::Expr,
+}
+
+#[derive(Debug, FromMeta, Clone)]
+pub(super) struct SplitInput {
+    /// The fields of the other type
+    into: IdentList,
+    /// The function receiving this field to build a tuple with every other field
+    with: syn::Expr,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct IdentList(Vec<syn::Ident>);
+
+impl FromMeta for IdentList {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        let syn::Expr::Array(array) = expr else {
+            return Err(darling::Error::unexpected_expr_type(expr));
+        };
+        let idents = array
+            .elems
+            .iter()
+            .map(syn::Ident::from_expr)
+            .collect::<darling::Result<Vec<_>>>()?;
+        if idents.is_empty() {
+            return Err(darling::Error::too_few_items(1).with_span(expr));
+        }
+        Ok(IdentList(idents))
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) => path
+                    .get_ident()
+                    .cloned()
+                    .ok_or_else(|| darling::Error::custom("Expected a field name").with_span(path)),
+                _ => Err(darling::Error::custom("Expected a field name").with_span(item)),
+            })
+            .collect::<darling::Result<Vec<_>>>()
+            .map(IdentList)
+    }
+}
+
+/// Extra fields or variants of the other type to ignore, optionally qualified with the type they belong to when
+/// deriving many types (like `Profile::name`)
+#[derive(Debug, Clone)]
+pub(super) struct IgnoreList(Vec<(Option<TypePathWrapper>, syn::Ident)>);
+
+impl IgnoreList {
+    fn parse(path: &syn::Path) -> darling::Result<(Option<TypePathWrapper>, syn::Ident)> {
+        let Some(last) = path.segments.last().filter(|s| s.arguments.is_empty()) else {
+            return Err(darling::Error::custom("Expected a field name").with_span(path));
+        };
+        if path.segments.len() == 1 && path.leading_colon.is_none() {
+            return Ok((None, last.ident.clone()));
+        }
+        let ty = syn::TypePath {
+            qself: None,
+            path: syn::Path {
+                leading_colon: path.leading_colon,
+                segments: path.segments.iter().take(path.segments.len() - 1).cloned().collect(),
+            },
+        };
+        Ok((Some(TypePathWrapper(ty)), last.ident.clone()))
+    }
+}
+
+impl FromMeta for IgnoreList {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        let syn::Expr::Array(array) = expr else {
+            return Err(darling::Error::unexpected_expr_type(expr));
+        };
+        let ignored = array
+            .elems
+            .iter()
+            .map(|elem| match elem {
+                syn::Expr::Path(path) if path.qself.is_none() => Self::parse(&path.path),
+                _ => Err(darling::Error::custom("Expected a field name").with_span(elem)),
+            })
+            .collect::<darling::Result<Vec<_>>>()?;
+        if ignored.is_empty() {
+            return Err(darling::Error::too_few_items(1).with_span(expr));
+        }
+        Ok(IgnoreList(ignored))
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) => Self::parse(path),
+                _ => Err(darling::Error::custom("Expected a field name").with_span(item)),
+            })
+            .collect::<darling::Result<Vec<_>>>()
+            .map(IgnoreList)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct TypePathList(Vec<TypePathWrapper>);
+
+impl FromMeta for TypePathList {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        TypePathWrapper::from_value(value).map(|ty| TypePathList(vec![ty]))
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        let syn::Expr::Array(array) = expr else {
+            return TypePathWrapper::from_expr(expr).map(|ty| TypePathList(vec![ty]));
+        };
+        let tys = array
+            .elems
+            .iter()
+            .map(TypePathWrapper::from_expr)
+            .collect::<darling::Result<Vec<_>>>()?;
+        if tys.is_empty() {
+            return Err(darling::Error::too_few_items(1).with_span(expr));
+        }
+        Ok(TypePathList(tys))
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) => Ok(TypePathWrapper(syn::TypePath {
+                    qself: None,
+                    path: path.clone(),
+                })),
+                _ => Err(darling::Error::custom("Expected a type path").with_span(item)),
+            })
+            .collect::<darling::Result<Vec<_>>>()
+            .map(TypePathList)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct FromPathInput {
+    /// The field of the other type
+    root: syn::Ident,
+    /// The nested fields to access, along with whether the previous one is an optional value to unwrap
+    segments: Vec<(bool, syn::Member)>,
+    /// Whether the last field is an optional value to unwrap
+    unwrap_last: bool,
+}
+
+impl FromPathInput {
+    fn parse(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Path(path_expr) if path_expr.qself.is_none() => match path_expr.path.get_ident() {
+                Some(root) => Ok(FromPathInput {
+                    root: root.clone(),
+                    segments: Vec::new(),
+                    unwrap_last: false,
+                }),
+                None => Err(darling::Error::custom("Expected a field name").with_span(expr)),
+            },
+            syn::Expr::Field(field_expr) => {
+                let mut path = Self::parse(&field_expr.base)?;
+                path.segments.push((path.unwrap_last, field_expr.member.clone()));
+                path.unwrap_last = false;
+                Ok(path)
+            }
+            syn::Expr::Try(try_expr) => {
+                let mut path = Self::parse(&try_expr.expr)?;
+                if path.unwrap_last {
+                    return Err(darling::Error::custom("Unexpected '?'").with_span(expr));
+                }
+                path.unwrap_last = true;
+                Ok(path)
+            }
+            _ => Err(darling::Error::custom("Expected a field path like `profile.contact.email`").with_span(expr)),
+        }
+    }
+
+    /// Whether any of the fields is an optional value to unwrap
+    fn is_optional(&self) -> bool {
+        self.unwrap_last || self.segments.iter().any(|(unwrap, _)| *unwrap)
+    }
+
+    /// Builds the statements unwrapping the optional values before the last field, along with the path they unwrap, and
+    /// the expression reading the nested field from them. Unwrapped values are bound to an ident derived from their
+    /// path, so they can be shared by many fields.
+    fn build(&self) -> (Vec<(String, TokenStream)>, TokenStream) {
+        let unwrap = |accessed: &TokenStream, described: &str| {
+            let msg = format!("Missing value for '{described}'");
+            quote!(#accessed.ok_or_else(|| ::anyhow::anyhow!(#msg))?)
+        };
+        let root = &self.root;
+        let mut unwraps = Vec::new();
+        let mut accessed = quote!(#root);
+        let mut described = root.to_string();
+        for (unwrap_previous, member) in &self.segments {
+            if *unwrap_previous {
+                let binding = format_ident!("__{}", described.replace("r#", "").replace('.', "_"));
+                let unwrapped = unwrap(&accessed, &described);
+                unwraps.push((described.clone(), quote!(let #binding = #unwrapped;)));
+                accessed = quote!(#binding);
+            }
+            accessed = quote!(#accessed.#member);
+            described = format!("{described}.{}", member.to_token_stream());
+        }
+        if self.unwrap_last {
+            accessed = unwrap(&accessed, &described);
+        }
+        (unwraps, accessed)
+    }
+}
+
+impl FromMeta for FromPathInput {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        if let syn::Lit::Str(s) = value {
+            Self::parse(&s.parse().map_err(darling::Error::custom)?)
+        } else {
+            Err(darling::Error::unexpected_lit_type(value))
+        }
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Lit(lit_expr) => Self::from_value(&lit_expr.lit),
+            _ => Self::parse(expr),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct FlattenInput {
+    /// The flattened fields, along with the name on the other type
+    fields: Vec<(syn::Ident, syn::Ident)>,
+    /// Whether the field is an option, flattened into optional fields
+    opt: bool,
+}
+
+impl FromMeta for FlattenInput {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut flatten = FlattenInput {
+            fields: Vec::new(),
+            opt: false,
+        };
+        let mut errors = darling::Error::accumulator();
+        for item in items {
+            match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("opt") => flatten.opt = true,
+                NestedMeta::Meta(syn::Meta::Path(path)) => match path.get_ident() {
+                    Some(field) => flatten.fields.push((field.clone(), field.clone())),
+                    None => errors.push(darling::Error::custom("Expected the field name").with_span(path)),
+                },
+                NestedMeta::Meta(syn::Meta::NameValue(nv)) => {
+                    let field = errors.handle(
+                        nv.path
+                            .get_ident()
+                            .cloned()
+                            .ok_or_else(|| darling::Error::custom("Expected the field name").with_span(&nv.path)),
+                    );
+                    let other = errors.handle(syn::Ident::from_expr(&nv.value));
+                    if let (Some(field), Some(other)) = (field, other) {
+                        flatten.fields.push((field, other));
+                    }
+                }
+                _ => errors.push(darling::Error::custom("Expected a field or 'opt'").with_span(item)),
+            }
+        }
+        if flatten.fields.is_empty() {
+            errors.push(darling::Error::too_few_items(1));
+        }
+        errors.finish_with(flatten)
+    }
+}
+
+impl FromMeta for UpgradeInput {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut upgrade = UpgradeInput {
+            versions: Vec::new(),
+            fallible: false,
+            wrapper: None,
+        };
+        let mut errors = darling::Error::accumulator();
+        for item in items {
+            match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("try") => upgrade.fallible = true,
+                NestedMeta::Meta(syn::Meta::Path(path)) => upgrade.versions.push(SpannedValue::new(
+                    TypePathWrapper(syn::TypePath {
+                        qself: None,
+                        path: path.clone(),
+                    }),
+                    path.span(),
+                )),
+                NestedMeta::Lit(lit) => {
+                    if let Some(version) = errors.handle(TypePathWrapper::from_value(lit)) {
+                        upgrade.versions.push(SpannedValue::new(version, lit.span()));
+                    }
+                }
+                NestedMeta::Meta(syn::Meta::NameValue(nv)) if nv.path.is_ident("wrapper") => {
+                    if let Some(wrapper) = errors.handle(syn::Ident::from_expr(&nv.value)) {
+                        upgrade.wrapper = Some(wrapper);
+                    }
+                }
+                _ => errors.push(darling::Error::custom("Expected a type, 'try' or 'wrapper'").with_span(item)),
+            }
+        }
+        if upgrade.versions.is_empty() {
+            errors.push(darling::Error::too_few_items(1));
+        }
+        errors.finish_with(upgrade)
+    }
+}
+
+impl MapperOpts {
+    /// The `str` derive, at most one
+    pub(super) fn str_item(&self) -> Option<&SpannedValue<StrInput>> {
+        let mut str_items = self.items.iter().filter_map(|i| match i {
+            DeriveItemInput::Str(item) => Some(item),
+            _ => None,
+        });
+        let first = str_items.next();
+        for item in str_items {
+            emit_error!(item.span(), "The 'str' derive is duplicated")
+        }
+        first
+    }
+
+    /// The `int` derive, at most one
+    pub(super) fn int_item(&self) -> Option<&SpannedValue<IntInput>> {
+        let mut int_items = self.items.iter().filter_map(|i| match i {
+            DeriveItemInput::Int(item) => Some(item),
+            _ => None,
+        });
+        let first = int_items.next();
+        for item in int_items {
+            emit_error!(item.span(), "The 'int' derive is duplicated")
+        }
+        first
+    }
+
+    /// Retrieve the [ItemInput] of the [MapperOpts]
+    pub(super) fn items(&self) -> Vec<ItemInput> {
+        if !self.items.is_empty() {
+            // If there are multiple derives, the root-level fields are not allowed
+            if let Some(path) = self.path.as_ref() {
+                emit_error!(path.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(from) = self.from.as_ref() {
+                emit_error!(from.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(into) = self.into.as_ref() {
+                emit_error!(into.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(try_from) = self.try_from.as_ref() {
+                emit_error!(try_from.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(try_into) = self.try_into.as_ref() {
+                emit_error!(try_into.span(), "Illegal attribute when 'derive' is set")
+            }
+            if self.ignore_extra.is_present() {
+                emit_error!(self.ignore_extra.span(), "Illegal attribute when 'derive' is set")
+            }
+            if self.fill_default.is_present() {
+                emit_error!(self.fill_default.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(ignore) = self.ignore.as_ref() {
+                emit_error!(ignore.span(), "Illegal attribute when 'derive' is set")
+            }
+            if !self.add.is_empty() {
+                for i in &self.add {
+                    emit_error!(i.field.span(), "Illegal attribute when 'derive' is set")
+                }
+            }
+            if !self.convert.is_empty() {
+                for c in &self.convert {
+                    emit_error!(c.src.span(), "Illegal attribute when 'derive' is set")
+                }
+            }
+            if let Some(via) = self.via.as_ref() {
+                emit_error!(via.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(variant) = self.variant.as_ref() {
+                emit_error!(variant.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(default) = self.default.as_ref() {
+                emit_error!(default.span(), "Illegal attribute when 'derive' is set")
+            }
+            if let Some(span) = self.rename_rules.span() {
+                emit_error!(span, "Illegal attribute when 'derive' is set")
+            }
+            // Verify there same type is not duplicated
+            let items = self
+                .items
+                .iter()
+                .filter_map(|i| match i {
+                    DeriveItemInput::Type(item) => Some(item.as_ref()),
+                    DeriveItemInput::Str(_) | DeriveItemInput::Int(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let paths = items.iter().map(|i| &i.path).collect::<Vec<_>>();
+            for i in 0..paths.len() {
+                for j in 0..paths.len() {
+                    if i != j && paths[i].as_ref() == paths[j].as_ref() {
+                        emit_error!(paths[j].span(), "This type is duplicated")
+                    }
+                }
+            }
+            // Return all of the items
+            items.into_iter().cloned().collect()
+        } else if let Some(path) = self.path.as_ref() {
+            // If there are a single derive, wrap it on a vec
+            vec![ItemInput {
+                path: path.clone(),
+                from: self.from.clone(),
+                into: self.into.clone(),
+                try_from: self.try_from.clone(),
+                try_into: self.try_into.clone(),
+                ignore_extra: self.ignore_extra,
+                fill_default: self.fill_default,
+                ignore: self.ignore.clone(),
+                add: self.add.clone(),
+                convert: self.convert.clone(),
+                via: self.via.clone(),
+                variant: self.variant.clone(),
+                default: self.default.clone(),
+                rename_rules: self.rename_rules.clone(),
+            }]
+        } else if self.upgrade_from.is_some() {
+            // Upgrade chains doesn't require any derive
+            Vec::new()
+        } else {
+            // If there are no derives, abort
+            abort_call_site!("One of 'ty' or 'derive' must be set")
+        }
+    }
+}
+
+impl ItemInput {
+    /// Extra fields or variants of the other type to ignore
+    pub(super) fn ignored(&self) -> Vec<&syn::Ident> {
+        self.ignore
+            .iter()
+            .flat_map(|i| &i.0)
+            .filter(|(ty, _)| ty.is_none())
+            .map(|(_, ident)| ident)
+            .collect()
+    }
+
+    /// Extra fields of the given part of the other type to ignore, when deriving many types (unqualified ones belong to
+    /// the first one)
+    pub(super) fn ignored_in_part(&self, part: usize) -> Vec<&syn::Ident> {
+        let parts = self.path.parts();
+        self.ignore
+            .iter()
+            .flat_map(|i| &i.0)
+            .filter(|(ty, _)| match ty {
+                Some(ty) => parts.get(part).is_some_and(|p| *p == ty.as_ref()),
+                None => part == 0,
+            })
+            .map(|(_, ident)| ident)
+            .collect()
+    }
+
+    /// Validates the input is well formed, emitting errors if not
+    pub(super) fn validate(&self, is_enum: bool) {
+        // At least one kind of derive must be set
+        if self.from.is_none() && self.into.is_none() && self.try_from.is_none() && self.try_into.is_none() {
+            emit_error!(
+                self.path.span(),
+                "One of 'from', 'into', 'try_from' or 'try_into' must be set"
+            );
+        }
+        // If there are additional items without a default value (for structs only)
+        let items = self.add.iter().filter(|a| a.default.is_none()).collect::<Vec<_>>();
+        if !items.is_empty() && !is_enum {
+            // into and try_into must have the custom flag if enabled and ty must be set
+            let mut has_into = false;
+            let mut missing_custom = false;
+            if let Some(into) = self.into.as_deref() {
+                has_into = true;
+                if into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
+                    missing_custom = true;
+                }
+            }
+            if let Some(try_into) = self.try_into.as_deref() {
+                has_into = true;
+                if try_into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
+                    missing_custom = true;
+                }
+            }
+            if missing_custom || has_into {
                 for e in items {
                     if missing_custom {
                         emit_error!(
@@ -339,86 +1384,1091 @@ impl ItemInput {
                     }
                 }
             }
-        }
-        // Verify additional variants for enums
-        if is_enum {
-            for a in &self.add {
-                if let Some(ty) = &a.ty {
-                    emit_error!(ty.span(), "Illegal attribute for enums")
+        }
+        // Composed derives just rely on the intermediate type conversions
+        if self.via.is_some() {
+            for derive in [&self.from, &self.into, &self.try_from, &self.try_into]
+                .into_iter()
+                .flatten()
+            {
+                if let Some(custom) = derive.as_ref().as_ref().explicit().and_then(|e| e.custom.as_ref()) {
+                    emit_error!(custom.span(), "Illegal attribute when 'via' is set")
+                }
+            }
+            for a in &self.add {
+                emit_error!(a.field.span(), "Illegal attribute when 'via' is set")
+            }
+            if self.ignore_extra.is_present() {
+                emit_error!(self.ignore_extra.span(), "Illegal attribute when 'via' is set")
+            }
+            if self.fill_default.is_present() {
+                emit_error!(self.fill_default.span(), "Illegal attribute when 'via' is set")
+            }
+            if let Some(ignore) = self.ignore.as_ref() {
+                emit_error!(ignore.span(), "Illegal attribute when 'via' is set")
+            }
+            for c in &self.convert {
+                emit_error!(c.src.span(), "Illegal attribute when 'via' is set")
+            }
+            if let Some(span) = self.rename_rules.span() {
+                emit_error!(span, "Illegal attribute when 'via' is set")
+            }
+        }
+        // Conversion rules apply on a single direction
+        let has_from = self.from.is_some() || self.try_from.is_some();
+        let has_into = self.into.is_some() || self.try_into.is_some();
+        for c in &self.convert {
+            if c.from.is_present() && c.into.is_present() {
+                emit_error!(c.into.span(), "Only one of 'from' or 'into' can be set");
+            } else if c.from.is_present() && !has_from {
+                emit_error!(c.from.span(), "'from' requires a 'from' or 'try_from' derive");
+            } else if c.into.is_present() && !has_into {
+                emit_error!(c.into.span(), "'into' requires an 'into' or 'try_into' derive");
+            } else if !c.from.is_present() && !c.into.is_present() && has_from && has_into {
+                emit_error!(
+                    c.src.span(),
+                    "Set either 'from' or 'into', as the derive has both directions"
+                );
+            }
+        }
+        // Getters are only supported when deriving from a single struct
+        let getters = |derive: &Override<DeriveInput>| derive.as_ref().explicit().map(|e| e.getters);
+        for getters in [&self.from, &self.try_from]
+            .into_iter()
+            .flatten()
+            .filter_map(|d| getters(d))
+        {
+            if getters.is_present() && (is_enum || self.via.is_some() || self.path.single().is_none()) {
+                emit_error!(
+                    getters.span(),
+                    "'getters' is only supported when deriving from a single struct"
+                );
+            }
+        }
+        for getters in [&self.into, &self.try_into]
+            .into_iter()
+            .flatten()
+            .filter_map(|d| getters(d))
+        {
+            if getters.is_present() {
+                emit_error!(
+                    getters.span(),
+                    "'getters' is only supported on 'from' and 'try_from' derives"
+                );
+            }
+        }
+        // Constructors are only supported when deriving into a single struct
+        for derive in [&self.from, &self.try_from].into_iter().flatten() {
+            if let Some(constructor) = DeriveInput::constructor(derive) {
+                emit_error!(
+                    constructor.span(),
+                    "'constructor' is only supported on 'into' and 'try_into' derives"
+                );
+            }
+        }
+        for derive in [&self.into, &self.try_into].into_iter().flatten() {
+            if let Some(constructor) = DeriveInput::constructor(derive)
+                && (is_enum || self.via.is_some() || self.path.single().is_none())
+            {
+                emit_error!(
+                    constructor.span(),
+                    "'constructor' is only supported when deriving into a single struct"
+                );
+            }
+        }
+        // Builders are only supported when deriving into a single struct
+        for derive in [&self.from, &self.try_from].into_iter().flatten() {
+            if let Some(explicit) = derive.as_ref().as_ref().explicit() {
+                for span in explicit
+                    .builder
+                    .as_ref()
+                    .map(|b| b.span())
+                    .into_iter()
+                    .chain(explicit.build.as_ref().map(|b| b.span()))
+                {
+                    emit_error!(span, "'builder' is only supported on 'into' and 'try_into' derives");
+                }
+                if explicit.params_builder.is_present() {
+                    emit_error!(
+                        explicit.params_builder.span(),
+                        "'params_builder' is only supported on 'into' and 'try_into' derives"
+                    );
+                }
+            }
+        }
+        for derive in [&self.into, &self.try_into].into_iter().flatten() {
+            if let Some(explicit) = derive.as_ref().as_ref().explicit() {
+                if let Some(build) = explicit.build.as_ref()
+                    && DeriveInput::builder(derive).is_none()
+                {
+                    emit_error!(build.span(), "'build' requires a 'builder'");
+                }
+                if explicit.params_builder.is_present() {
+                    let span = explicit.params_builder.span();
+                    if explicit.custom.is_none() {
+                        emit_error!(span, "'params_builder' requires 'custom'");
+                    }
+                    if is_enum || self.via.is_some() {
+                        emit_error!(span, "'params_builder' is only supported when deriving into structs");
+                    }
+                    // The parameters become setters and fields of the staged builder, along with its own members
+                    for a in self.add.iter().filter(|a| a.default.is_none()) {
+                        let field = a.field.as_ref();
+                        if field == "finish" || field == "__from" || field == "__marker" {
+                            emit_error!(
+                                a.field.span(),
+                                "'{}' is reserved by 'params_builder', provide it with a default value instead",
+                                field
+                            );
+                        }
+                    }
+                }
+                if let Some(builder) = explicit.builder.as_ref() {
+                    if explicit.constructor.is_some() {
+                        emit_error!(builder.span(), "'builder' can't be combined with 'constructor'");
+                    }
+                    if is_enum || self.via.is_some() || self.path.single().is_none() {
+                        emit_error!(
+                            builder.span(),
+                            "'builder' is only supported when deriving into a single struct"
+                        );
+                    }
+                }
+            }
+        }
+        // Structs can be mapped from or into a single variant of the other enum
+        if let Some(variant) = self.variant.as_ref() {
+            if is_enum {
+                emit_error!(variant.span(), "'variant' is only supported for structs");
+            }
+            if self.via.is_some() || self.path.single().is_none() {
+                emit_error!(
+                    variant.span(),
+                    "'variant' is only supported when deriving a single enum"
+                );
+            }
+            if self.from.is_some() && self.default.is_none() {
+                emit_error!(
+                    variant.span(),
+                    "'from' requires a 'default' for the other variants, use 'try_from' instead"
+                );
+            }
+            for derive in [&self.from, &self.try_from, &self.into, &self.try_into]
+                .into_iter()
+                .flatten()
+            {
+                if DeriveInput::uses_getters(derive)
+                    || DeriveInput::builder(derive).is_some()
+                    || DeriveInput::constructor(derive).is_some()
+                {
+                    emit_error!(
+                        variant.span(),
+                        "'variant' can't be combined with 'getters', 'builder' or 'constructor'"
+                    );
+                }
+            }
+            // The fields of the other variant can't be filled with the default value of the whole enum
+            if self.into.is_some() || self.try_into.is_some() {
+                for span in [&self.ignore_extra, &self.fill_default]
+                    .into_iter()
+                    .filter(|f| f.is_present())
+                    .map(|f| f.span())
+                {
+                    emit_error!(
+                        span,
+                        "'ignore_extra' and 'fill_default' can't be combined with 'variant' when deriving 'into' or \
+                         'try_into', use 'add' with a default value instead"
+                    );
+                }
+            }
+        }
+        if let Some(default) = self.default.as_ref() {
+            if self.variant.is_none() {
+                emit_error!(default.span(), "'default' requires a 'variant'");
+            } else if self.from.is_none() && self.try_from.is_none() {
+                emit_error!(
+                    default.span(),
+                    "'default' is only supported on 'from' and 'try_from' derives"
+                );
+            }
+        }
+        // Derives from plain tuples are only supported for structs, mapping fields by position
+        if let DeriveTy::TupleType(_) = self.path.as_ref() {
+            if is_enum {
+                emit_error!(self.path.span(), "Plain tuple types are only supported for structs");
+            }
+            if self.via.is_some() {
+                emit_error!(self.path.span(), "Plain tuple types can't be combined with 'via'");
+            }
+            if let Some(ignore) = self.ignore.as_ref() {
+                emit_error!(ignore.span(), "Plain tuple types can't be combined with 'ignore'");
+            }
+            for a in &self.add {
+                emit_error!(a.field.span(), "Plain tuple types can't be combined with 'add'");
+            }
+            for derive in [&self.from, &self.try_from, &self.into, &self.try_into]
+                .into_iter()
+                .flatten()
+            {
+                if let Some(custom) = derive.as_ref().as_ref().explicit().and_then(|e| e.custom.as_ref()) {
+                    emit_error!(custom.span(), "Plain tuple types can't be combined with 'custom'");
+                }
+            }
+        }
+        // Derives from many types are only supported for structs
+        if let DeriveTy::Tuple(_) = self.path.as_ref() {
+            if is_enum {
+                emit_error!(self.path.span(), "Multiple types are only supported for structs");
+            }
+            if self.via.is_some() {
+                emit_error!(self.path.span(), "Multiple types can't be combined with 'via'");
+            }
+            if self
+                .path
+                .parts()
+                .iter()
+                .any(|p| p.path.segments.iter().any(|s| !s.arguments.is_none()))
+            {
+                emit_error!(
+                    self.path.span(),
+                    "Generic parts are not supported when deriving many types"
+                );
+            }
+        }
+        // Ignored fields can only be qualified with one of the types of the derive, when deriving many
+        if let Some(ignore) = self.ignore.as_ref() {
+            for ty in ignore.0.iter().filter_map(|(ty, _)| ty.as_ref()) {
+                if self.path.has_element(ty) {
+                    emit_quoted_tuple_error(ignore.span(), ty);
+                } else if self.path.parts().len() < 2 {
+                    emit_error!(
+                        ignore.span(),
+                        "Ignored fields can only be qualified when deriving many types"
+                    );
+                } else if !self.path.parts().iter().any(|p| *p == ty.as_ref()) {
+                    emit_error!(
+                        ignore.span(),
+                        "'{}' must be one of the types of the derive",
+                        ty.to_token_stream()
+                    );
+                }
+            }
+        }
+        // Additional fields must belong to one of the types of the derive
+        for a in &self.add {
+            if let Some(source) = a.source.as_ref() {
+                if self.path.has_element(source.as_ref()) {
+                    emit_quoted_tuple_error(source.span(), source.as_ref());
+                } else if !self.path.parts().iter().any(|p| *p == source.as_ref().as_ref()) {
+                    emit_error!(source.span(), "'source' must be one of the types of the derive");
+                } else if self.from.is_none() && self.try_from.is_none() {
+                    emit_error!(
+                        source.span(),
+                        "'source' is only supported on 'from' and 'try_from' derives"
+                    );
+                }
+            }
+            if let Some(target) = a.target.as_ref() {
+                if self.path.has_element(target.as_ref()) {
+                    emit_quoted_tuple_error(target.span(), target.as_ref());
+                } else if !self.path.parts().iter().any(|p| *p == target.as_ref().as_ref()) {
+                    emit_error!(target.span(), "'target' must be one of the types of the derive");
+                } else if self.into.is_none() && self.try_into.is_none() {
+                    emit_error!(
+                        target.span(),
+                        "'target' is only supported on 'into' and 'try_into' derives"
+                    );
+                }
+            }
+            if let Some(index) = a.index.as_ref() {
+                emit_error!(
+                    index.span(),
+                    "'index' is only supported on additional fields of variants"
+                );
+            }
+        }
+        // Verify additional variants for enums
+        if is_enum {
+            if self.fill_default.is_present() {
+                emit_error!(self.fill_default.span(), "Illegal attribute for enums")
+            }
+            for a in &self.add {
+                if let Some(ty) = &a.ty {
+                    emit_error!(ty.span(), "Illegal attribute for enums")
+                }
+                if a.default.is_none() && (self.from.is_some() || self.try_from.is_some()) {
+                    emit_error!(
+                        a.field.span(),
+                        "Missing mandatory `default` for enums when deriving `from` or `try_from`"
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl ItemVariantInput {
+    fn validate(&self, derives: &[ItemInput]) {
+        let derive = derives.iter().find(|d| d.path.as_ref() == self.path.as_ref());
+        if let Some(derive) = derive {
+            // Variants mapped from or into other variants must be derived on that direction
+            let has_from = derive.from.is_some() || derive.try_from.is_some();
+            let has_into = derive.into.is_some() || derive.try_into.is_some();
+            if let Some(from_variants) = self.from_variants.as_ref()
+                && !has_from
+            {
+                emit_error!(
+                    from_variants.span(),
+                    "'from_variants' is only supported on 'from' and 'try_from' derives"
+                );
+            }
+            if let Some(split_by) = self.split_by.as_ref()
+                && !has_from
+            {
+                emit_error!(
+                    split_by.span(),
+                    "'split_by' is only supported on 'from' and 'try_from' derives"
+                );
+            }
+            if let Some(into_variant) = self.into_variant.as_ref()
+                && !has_into
+            {
+                emit_error!(
+                    into_variant.span(),
+                    "'into_variant' is only supported on 'into' and 'try_into' derives"
+                );
+            }
+            // If there are additional items without a default value
+            let items = self.add.iter().filter(|a| a.default.is_none()).collect::<Vec<_>>();
+            if !items.is_empty() {
+                // into and try_into must have the custom flag if enabled and ty must be set
+                let mut has_into = false;
+                let mut missing_custom = false;
+                if let Some(into) = derive.into.as_deref() {
+                    has_into = true;
+                    if into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
+                        missing_custom = true;
+                    }
+                }
+                if let Some(try_into) = derive.try_into.as_deref() {
+                    has_into = true;
+                    if try_into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
+                        missing_custom = true;
+                    }
+                }
+                if missing_custom || has_into {
+                    for e in items {
+                        if missing_custom {
+                            emit_error!(
+                                e.field.span(),
+                                "Enable `default` here or include `custom` on `into` and `try_into` derives"
+                            );
+                        }
+                        if e.ty.is_none() && has_into {
+                            emit_error!(
+                                e.field.span(),
+                                "Provide a field type with `ty` if the field is not `default` to derive `into` and \
+                                 `try_into`"
+                            );
+                        }
+                    }
+                }
+            }
+            // If there are skipped variants without a default value
+            if let Some(skip) = self.skip.as_ref()
+                && Override::as_ref(skip)
+                    .explicit()
+                    .map(|e| e.default.is_none())
+                    .unwrap_or(true)
+            {
+                // into and try_into requires a default value
+                if derive.into.is_some() || derive.try_into.is_some() {
+                    emit_error!(
+                        skip.span(),
+                        "Enable `default` here required for `into` and `try_into` derives"
+                    );
+                }
+            }
+        } else {
+            emit_error!(self.path.span(), "There is no derive defined for this type");
+        }
+    }
+}
+
+impl VariantReceiver {
+    /// The string representation of the variant on `str` derives, either renamed or converted to the given case
+    pub(super) fn str_name(&self, case: Option<&CaseInput>) -> String {
+        if self.path.is_none()
+            && let Some(rename) = self.rename.as_deref()
+        {
+            return rename.to_string();
+        }
+        let ident = self.ident.to_string();
+        case.map(|c| c.convert(&ident)).unwrap_or(ident)
+    }
+
+    pub(super) fn validate(&self, derives: &[ItemInput]) {
+        if !self.items.is_empty() {
+            // If there are multiple derives, the root-level fields are not allowed
+            if let Some(path) = self.path.as_ref() {
+                emit_error!(path.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(rename) = self.rename.as_ref() {
+                emit_error!(rename.span(), "Illegal attribute if 'when' is set")
+            }
+            if !self.add.is_empty() {
+                for i in &self.add {
+                    emit_error!(i.field.span(), "Illegal attribute if 'when' is set")
+                }
+            }
+            if let Some(skip) = self.skip.as_ref() {
+                emit_error!(skip.span(), "Illegal attribute if 'when' is set")
+            }
+            if self.ignore_extra.is_present() {
+                emit_error!(self.ignore_extra.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(ignore) = self.ignore.as_ref() {
+                emit_error!(ignore.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(shape) = self.shape.as_ref() {
+                emit_error!(shape.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(from_variants) = self.from_variants.as_ref() {
+                emit_error!(from_variants.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(into_variant) = self.into_variant.as_ref() {
+                emit_error!(into_variant.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(split_by) = self.split_by.as_ref() {
+                emit_error!(split_by.span(), "Illegal attribute if 'when' is set")
+            }
+            // Verify there same type is not duplicated
+            let paths = self.items.iter().map(|i| &i.path).collect::<Vec<_>>();
+            for i in 0..paths.len() {
+                for j in 0..paths.len() {
+                    if i != j && paths[i].as_ref() == paths[j].as_ref() {
+                        emit_error!(paths[j].span(), "This type is duplicated")
+                    }
+                }
+            }
+        }
+
+        if let Some(path) = &self.path {
+            ItemVariantInput {
+                path: path.clone(),
+                rename: self.rename.clone(),
+                add: self.add.to_vec(),
+                skip: self.skip.clone(),
+                ignore_extra: self.ignore_extra,
+                ignore: self.ignore.clone(),
+                shape: self.shape,
+                from_variants: self.from_variants.clone(),
+                into_variant: self.into_variant.clone(),
+                split_by: self.split_by.clone(),
+            }
+            .validate(derives);
+        } else {
+            for d in derives {
+                ItemVariantInput {
+                    path: d.path.clone(),
+                    rename: self.rename.clone(),
+                    add: self.add.to_vec(),
+                    skip: self.skip.clone(),
+                    ignore_extra: self.ignore_extra,
+                    ignore: self.ignore.clone(),
+                    shape: self.shape,
+                    from_variants: self.from_variants.clone(),
+                    into_variant: self.into_variant.clone(),
+                    split_by: self.split_by.clone(),
+                }
+                .validate(derives);
+            }
+        }
+        for item in self.items.iter() {
+            item.validate(derives);
+        }
+
+        // The fields must be mapped by position or name, depending on the shape of the other variant
+        for d in derives {
+            let derive_ty = d.path.as_ref();
+            let shape = self.shape_for(derive_ty);
+            let is_tuple = shape.map(|s| s == ShapeInput::Tuple).unwrap_or(self.fields.is_tuple());
+            for f in self.fields.iter().filter(|f| f.skip_for(derive_ty).is_none()) {
+                let span = f.ident.as_ref().map(|i| i.span()).unwrap_or_else(|| f.ty.span());
+                match shape {
+                    Some(ShapeInput::Tuple) if f.name_for(derive_ty).is_some() => {
+                        emit_error!(span, "'name' can't be combined with a tuple shape");
+                    }
+                    Some(ShapeInput::Named) if f.index_for(derive_ty).is_some() => {
+                        emit_error!(span, "'index' can't be combined with a named shape");
+                    }
+                    Some(ShapeInput::Named) if f.ident.is_none() && f.name_for(derive_ty).is_none() => {
+                        emit_error!(span, "Unnamed fields need a 'name' when the variant shape is named");
+                    }
+                    _ => (),
+                }
+            }
+            for a in self.additional_for(derive_ty).into_iter().flatten() {
+                match a.index.as_ref() {
+                    Some(index) if !is_tuple => {
+                        emit_error!(
+                            index.span(),
+                            "'index' is only supported when the other variant is a tuple"
+                        );
+                    }
+                    None if shape == Some(ShapeInput::Tuple) => {
+                        emit_error!(
+                            a.field.span(),
+                            "Additional fields need an 'index' when the variant shape is tuple"
+                        );
+                    }
+                    _ => (),
+                }
+            }
+            // Every element of the other variant must be mapped at most once
+            if is_tuple {
+                let members = self.other_members_for(derive_ty);
+                let mut elements = HashSet::new();
+                let fields = self
+                    .fields
+                    .iter()
+                    .zip(members)
+                    .filter(|(f, _)| f.skip_for(derive_ty).is_none())
+                    .map(|(f, member)| {
+                        let span = f.ident.as_ref().map(|i| i.span()).unwrap_or_else(|| f.ty.span());
+                        (member.to_string(), span)
+                    });
+                let additional = self
+                    .additional_for(derive_ty)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|a| a.index.as_ref().map(|index| (index.to_string(), index.span())));
+                for (element, span) in fields.chain(additional) {
+                    if !elements.insert(element.clone()) {
+                        emit_error!(span, "Element {} of the variant is already mapped", element);
+                    }
+                }
+            }
+        }
+        // Variants mapped from many other variants must know which one they're mapped into
+        for d in derives.iter().filter(|d| d.into.is_some() || d.try_into.is_some()) {
+            let derive_ty = d.path.as_ref();
+            let from_variants = self.source_variants_for(derive_ty);
+            if from_variants.len() > 1
+                && self.explicit_target_for(derive_ty).is_none()
+                && !from_variants.contains(&self.other_ident_for(derive_ty))
+            {
+                emit_error!(
+                    self.ident.span(),
+                    "An 'into_variant' is required to derive 'into' or 'try_into' from many variants"
+                );
+            }
+        }
+        self.fields.iter().for_each(|f| f.validate(derives));
+    }
+
+    /// The name of the variant on the other enum, either renamed or the same one
+    pub(super) fn other_ident_for(&self, derive_path: &DeriveTy) -> &syn::Ident {
+        self.rename_for(derive_path).unwrap_or(&self.ident)
+    }
+
+    /// The variants of the other enum this one is mapped from
+    pub(super) fn source_variants_for(&self, derive_path: &DeriveTy) -> Vec<&syn::Ident> {
+        let from_variants = if let Some(item) = self.items.iter().find(|i| i.path.as_ref() == derive_path) {
+            item.from_variants.as_deref()
+        } else if self.path.as_ref().is_none_or(|p| p.as_ref() == derive_path) {
+            self.from_variants.as_deref()
+        } else {
+            None
+        };
+        match from_variants {
+            Some(from_variants) => from_variants.0.iter().collect(),
+            None => vec![self.other_ident_for(derive_path)],
+        }
+    }
+
+    /// The variant of the other enum this one is mapped into: the explicit one, the single one it's mapped from or the
+    /// same (or renamed) one
+    pub(super) fn target_variant_for(&self, derive_path: &DeriveTy) -> &syn::Ident {
+        if let Some(into_variant) = self.explicit_target_for(derive_path) {
+            return into_variant;
+        }
+        match self.source_variants_for(derive_path).as_slice() {
+            [from_variant] => from_variant,
+            _ => self.other_ident_for(derive_path),
+        }
+    }
+
+    fn explicit_target_for(&self, derive_path: &DeriveTy) -> Option<&syn::Ident> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.into_variant.as_deref();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.into_variant.as_deref()
+            } else {
+                None
+            }
+        } else {
+            self.into_variant.as_deref()
+        }
+    }
+
+    pub(super) fn split_by_for(&self, derive_path: &DeriveTy) -> Option<&syn::Expr> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.split_by.as_deref();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.split_by.as_deref()
+            } else {
+                None
+            }
+        } else {
+            self.split_by.as_deref()
+        }
+    }
+
+    pub(super) fn rename_for(&self, derive_path: &DeriveTy) -> Option<&syn::Ident> {
+        let rename = if let Some(item) = self.items.iter().find(|i| i.path.as_ref() == derive_path) {
+            item.rename.as_deref()
+        } else if self.path.as_ref().is_none_or(|p| p.as_ref() == derive_path) {
+            self.rename.as_deref()
+        } else {
+            None
+        };
+        // Explicit renames take precedence over the rename rules
+        rename.or_else(|| {
+            self.renamed
+                .iter()
+                .find(|(path, _)| path == derive_path)
+                .map(|(_, renamed)| renamed)
+        })
+    }
+
+    /// Computes the name on the other type of the given derive following its rename rules, if any
+    pub(super) fn apply_rename_rules(&mut self, derive: &ItemInput) {
+        if let Some(renamed) = derive.rename_rules.rename(&self.ident) {
+            self.renamed.push((derive.path.as_ref().clone(), renamed));
+        }
+    }
+
+    pub(super) fn additional_for(&self, derive_path: &DeriveTy) -> Option<&Vec<AddInput>> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                if item.add.is_empty() {
+                    return None;
+                } else {
+                    return Some(item.add.as_ref());
+                }
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                if self.add.is_empty() {
+                    None
+                } else {
+                    Some(self.add.as_ref())
+                }
+            } else {
+                None
+            }
+        } else if self.add.is_empty() {
+            None
+        } else {
+            Some(self.add.as_ref())
+        }
+    }
+
+    pub(super) fn skip_for(&self, derive_path: &DeriveTy) -> Option<&Override<SkipInput>> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.skip.as_deref();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.skip.as_deref()
+            } else {
+                None
+            }
+        } else {
+            self.skip.as_deref()
+        }
+    }
+
+    pub(super) fn ignored_for(&self, derive_path: &DeriveTy) -> &[syn::Ident] {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.ignore.as_deref().map(|i| i.0.as_slice()).unwrap_or_default();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.ignore.as_deref().map(|i| i.0.as_slice()).unwrap_or_default()
+            } else {
+                &[]
+            }
+        } else {
+            self.ignore.as_deref().map(|i| i.0.as_slice()).unwrap_or_default()
+        }
+    }
+
+    pub(super) fn ignore_extra_for(&self, derive_path: &DeriveTy) -> bool {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.ignore_extra.is_present();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.ignore_extra.is_present()
+            } else {
+                false
+            }
+        } else {
+            self.ignore_extra.is_present()
+        }
+    }
+
+    pub(super) fn shape_for(&self, derive_path: &DeriveTy) -> Option<ShapeInput> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.shape.as_deref().copied();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.shape.as_deref().copied()
+            } else {
+                None
+            }
+        } else {
+            self.shape.as_deref().copied()
+        }
+    }
+
+    /// Whether the fields of the variant are accessed by their member (`Variant { 0: .. }`), to be reshaped or mapped
+    /// from or into positional fields of the other variant
+    pub(super) fn maps_by_member_for(&self, derive_path: &DeriveTy) -> bool {
+        self.shape_for(derive_path).is_some()
+            || self
+                .fields
+                .iter()
+                .any(|f| f.index_for(derive_path).is_some() || f.name_for(derive_path).is_some())
+            || self
+                .additional_for(derive_path)
+                .is_some_and(|add| add.iter().any(|a| a.index.is_some()))
+    }
+
+    /// The member of the other variant each field is mapped from or into: the explicit index or the next position
+    /// when its shape is tuple, or the same member as for structs otherwise
+    pub(super) fn other_members_for(&self, derive_path: &DeriveTy) -> Vec<TokenStream> {
+        let is_tuple = self.shape_for(derive_path) == Some(ShapeInput::Tuple);
+        let mut next = 0;
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(ix, f)| {
+                if is_tuple && f.skip_for(derive_path).is_none() {
+                    let index = f.index_for(derive_path).unwrap_or(next);
+                    next += 1;
+                    syn::Index::from(index).to_token_stream()
+                } else {
+                    f.other_member_for(derive_path, ix)
+                }
+            })
+            .collect()
+    }
+}
+
+impl ItemFieldInput {
+    /// The spans of every attribute set, other than the path
+    fn attribute_spans(&self) -> Vec<Span> {
+        [
+            self.rename.as_ref().map(|a| a.span()),
+            self.skip.as_ref().map(|a| a.span()),
+            self.other_ty.as_ref().map(|a| a.span()),
+            self.flatten.as_ref().map(|a| a.span()),
+            self.getter.as_ref().map(|a| a.span()),
+            self.setter.as_ref().map(|a| a.span()),
+            self.index.as_ref().map(|a| a.span()),
+            self.name.as_ref().map(|a| a.span()),
+            self.from_path.as_ref().map(|a| a.span()),
+            self.combine.as_ref().map(|a| a.span()),
+            self.split.as_ref().map(|a| a.span()),
+            self.source.as_ref().map(|a| a.span()),
+            self.target.as_ref().map(|a| a.span()),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.hint.spans())
+        .collect()
+    }
+
+    fn validate(&self, span: Span, derives: &[ItemInput]) {
+        let derive = derives.iter().find(|d| d.path.as_ref() == self.path.as_ref());
+        if let Some(derive) = derive {
+            // If there are skipped fields without a default value (and they're not populated by the default of self)
+            if let Some(skip) = self.skip.as_ref()
+                && !derive.fill_default.is_present()
+                && Override::as_ref(skip)
+                    .explicit()
+                    .map(|e| e.default.is_none())
+                    .unwrap_or(true)
+            {
+                // from and try_from must have the custom flag if enabled
+                let mut missing_custom = false;
+                if let Some(from) = derive.from.as_deref()
+                    && from.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true)
+                {
+                    missing_custom = true;
+                }
+                if let Some(try_from) = derive.try_from.as_deref()
+                    && try_from.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true)
+                {
+                    missing_custom = true;
+                }
+                if missing_custom {
+                    emit_error!(
+                        skip.span(),
+                        "Enable `default` here or include `custom` on `from` and `try_from` derives"
+                    );
+                }
+            }
+            // validate only one hint
+            let mut hint_count = 0;
+            if self.hint.with.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.from_with.is_some() || self.hint.into_with.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.codec.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.via_adapter.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.via.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.opt.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.iter.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.map.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.boxed.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.inner.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.r#box.is_some() {
+                hint_count += 1;
+            }
+            if self.hint.unbox.is_some() {
+                hint_count += 1;
+            }
+            if let Some(flatten) = self.flatten.as_ref()
+                && (hint_count > 0 || self.rename.is_some())
+            {
+                emit_error!(flatten.span(), "'flatten' can't be combined with 'rename' or any hint");
+            }
+            if let Some(from_path) = self.from_path.as_ref()
+                && (self.rename.is_some() || self.skip.is_some() || self.flatten.is_some())
+            {
+                emit_error!(
+                    from_path.span(),
+                    "'from_path' can't be combined with 'rename', 'skip' or 'flatten'"
+                );
+            }
+            for composite in self
+                .combine
+                .as_ref()
+                .map(|c| c.span())
+                .into_iter()
+                .chain(self.split.as_ref().map(|s| s.span()))
+            {
+                if self.rename.is_some() || self.skip.is_some() || self.flatten.is_some() || self.from_path.is_some() {
+                    emit_error!(
+                        composite,
+                        "'combine' and 'split' can't be combined with 'rename', 'skip', 'flatten' or 'from_path'"
+                    );
+                }
+            }
+            // Composed fields would be mapped directly on the other direction
+            if let Some(combine) = self.combine.as_ref()
+                && self.split.is_none()
+                && (derive.into.is_some() || derive.try_into.is_some())
+            {
+                emit_error!(
+                    combine.span(),
+                    "'combine' requires a 'split' when deriving 'into' or 'try_into', derive them separately otherwise"
+                );
+            }
+            if let Some(split) = self.split.as_ref()
+                && self.combine.is_none()
+                && (derive.from.is_some() || derive.try_from.is_some())
+            {
+                emit_error!(
+                    split.span(),
+                    "'split' requires a 'combine' when deriving 'from' or 'try_from', derive them separately otherwise"
+                );
+            }
+            if let Some(from_path) = self.from_path.as_ref()
+                && from_path.is_optional()
+                && derive.from.is_some()
+            {
+                emit_error!(
+                    from_path.span(),
+                    "Optional fields are only supported on 'try_from' derives"
+                );
+            }
+            if let Some(source) = self.source.as_ref() {
+                if derive.path.has_element(source.as_ref()) {
+                    emit_quoted_tuple_error(source.span(), source.as_ref());
+                } else if !derive.path.parts().iter().any(|p| *p == source.as_ref().as_ref()) {
+                    emit_error!(source.span(), "'source' must be one of the types of the derive");
+                } else if derive.from.is_none() && derive.try_from.is_none() {
+                    emit_error!(
+                        source.span(),
+                        "'source' is only supported on 'from' and 'try_from' derives"
+                    );
+                }
+            }
+            let uses_getters = [&derive.from, &derive.try_from]
+                .into_iter()
+                .flatten()
+                .any(|d| DeriveInput::uses_getters(d));
+            if let Some(getter) = self.getter.as_ref()
+                && !uses_getters
+            {
+                emit_error!(
+                    getter.span(),
+                    "'getter' requires 'getters' on 'from' or 'try_from' derives"
+                );
+            }
+            if let Some(setter) = self.setter.as_ref()
+                && ![&derive.into, &derive.try_into]
+                    .into_iter()
+                    .flatten()
+                    .any(|d| DeriveInput::builder(d).is_some())
+            {
+                emit_error!(
+                    setter.span(),
+                    "'setter' requires a 'builder' on 'into' or 'try_into' derives"
+                );
+            }
+            for span in self
+                .index
+                .as_ref()
+                .map(|i| i.span())
+                .into_iter()
+                .chain(self.name.as_ref().map(|n| n.span()))
+            {
+                if self.skip.is_some()
+                    || self.flatten.is_some()
+                    || self.from_path.is_some()
+                    || self.combine.is_some()
+                    || self.split.is_some()
+                {
+                    emit_error!(
+                        span,
+                        "'index' and 'name' can't be combined with 'skip', 'flatten', 'from_path', 'combine' or \
+                         'split'"
+                    );
                 }
-                if a.default.is_none() && (self.from.is_some() || self.try_from.is_some()) {
+                if !matches!(derive.path.as_ref(), DeriveTy::Single(_) | DeriveTy::TupleType(_)) {
+                    emit_error!(span, "'index' and 'name' can't be combined with multiple types");
+                }
+                if uses_getters
+                    || [&derive.into, &derive.try_into]
+                        .into_iter()
+                        .flatten()
+                        .any(|d| DeriveInput::builder(d).is_some() || DeriveInput::constructor(d).is_some())
+                {
                     emit_error!(
-                        a.field.span(),
-                        "Missing mandatory `default` for enums when deriving `from` or `try_from`"
-                    )
+                        span,
+                        "'index' and 'name' can't be combined with 'getters', 'builder' or 'constructor'"
+                    );
                 }
             }
-        }
-    }
-}
-
-impl ItemVariantInput {
-    fn validate(&self, derives: &[ItemInput]) {
-        let derive = derives.iter().find(|d| d.path.as_ref() == self.path.as_ref());
-        if let Some(derive) = derive {
-            // If there are additional items without a default value
-            let items = self.add.iter().filter(|a| a.default.is_none()).collect::<Vec<_>>();
-            if !items.is_empty() {
-                // into and try_into must have the custom flag if enabled and ty must be set
-                let mut has_into = false;
-                let mut missing_custom = false;
-                if let Some(into) = derive.into.as_deref() {
-                    has_into = true;
-                    if into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
-                        missing_custom = true;
-                    }
+            if let DeriveTy::TupleType(_) = derive.path.as_ref() {
+                if let Some(name) = self.name.as_ref() {
+                    emit_error!(
+                        name.span(),
+                        "'name' is not supported on plain tuple types, use 'index' instead"
+                    );
                 }
-                if let Some(try_into) = derive.try_into.as_deref() {
-                    has_into = true;
-                    if try_into.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true) {
-                        missing_custom = true;
-                    }
+                if let Some(rename) = self.rename.as_ref() {
+                    emit_error!(
+                        rename.span(),
+                        "'rename' is not supported on plain tuple types, use 'index' instead"
+                    );
                 }
-                if missing_custom || has_into {
-                    for e in items {
-                        if missing_custom {
-                            emit_error!(
-                                e.field.span(),
-                                "Enable `default` here or include `custom` on `into` and `try_into` derives"
-                            );
-                        }
-                        if e.ty.is_none() && has_into {
-                            emit_error!(
-                                e.field.span(),
-                                "Provide a field type with `ty` if the field is not `default` to derive `into` and \
-                                 `try_into`"
-                            );
-                        }
-                    }
+                for span in self
+                    .flatten
+                    .as_ref()
+                    .map(|f| f.span())
+                    .into_iter()
+                    .chain(self.from_path.as_ref().map(|f| f.span()))
+                    .chain(self.combine.as_ref().map(|c| c.span()))
+                    .chain(self.split.as_ref().map(|s| s.span()))
+                {
+                    emit_error!(
+                        span,
+                        "'flatten', 'from_path', 'combine' and 'split' are not supported on plain tuple types"
+                    );
                 }
             }
-            // If there are skipped variants without a default value
-            if let Some(skip) = self.skip.as_ref()
-                && Override::as_ref(skip)
-                    .explicit()
-                    .map(|e| e.default.is_none())
-                    .unwrap_or(true)
+            if let Some(from_path) = self.from_path.as_ref()
+                && uses_getters
             {
-                // into and try_into requires a default value
-                if derive.into.is_some() || derive.try_into.is_some() {
+                emit_error!(from_path.span(), "'from_path' can't be combined with 'getters'");
+            }
+            if let Some(target) = self.target.as_ref() {
+                if let Some(element) = target.0.iter().find(|t| derive.path.has_element(t)) {
+                    emit_quoted_tuple_error(target.span(), element);
+                } else if target
+                    .0
+                    .iter()
+                    .any(|t| !derive.path.parts().iter().any(|p| *p == t.as_ref()))
+                {
+                    emit_error!(target.span(), "'target' must be one of the types of the derive");
+                } else if derive.into.is_none() && derive.try_into.is_none() {
                     emit_error!(
-                        skip.span(),
-                        "Enable `default` here required for `into` and `try_into` derives"
+                        target.span(),
+                        "'target' is only supported on 'into' and 'try_into' derives"
+                    );
+                } else if target.0.len() > 1 && (self.flatten.is_some() || self.split.is_some()) {
+                    emit_error!(
+                        target.span(),
+                        "Multiple targets can't be combined with 'flatten' or 'split'"
                     );
                 }
             }
+            if hint_count > 1 {
+                emit_error!(
+                    span,
+                    "Only one of 'with', 'into_with'/'from_with', 'codec', 'via_adapter', 'via', 'opt', 'iter', \
+                     'map', 'boxed', 'inner', 'box' or 'unbox' can be set"
+                );
+            }
         } else {
             emit_error!(self.path.span(), "There is no derive defined for this type");
         }
     }
 }
-
-impl VariantReceiver {
+impl FieldReceiver {
     pub(super) fn validate(&self, derives: &[ItemInput]) {
         if !self.items.is_empty() {
             // If there are multiple derives, the root-level fields are not allowed
@@ -428,16 +2478,65 @@ impl VariantReceiver {
             if let Some(rename) = self.rename.as_ref() {
                 emit_error!(rename.span(), "Illegal attribute if 'when' is set")
             }
-            if !self.add.is_empty() {
-                for i in &self.add {
-                    emit_error!(i.field.span(), "Illegal attribute if 'when' is set")
-                }
-            }
             if let Some(skip) = self.skip.as_ref() {
                 emit_error!(skip.span(), "Illegal attribute if 'when' is set")
             }
-            if self.ignore_extra.is_present() {
-                emit_error!(self.ignore_extra.span(), "Illegal attribute if 'when' is set")
+            if let Some(with) = self.hint.with.as_ref() {
+                emit_error!(with.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(into_with) = self.hint.into_with.as_ref() {
+                emit_error!(into_with.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(from_with) = self.hint.from_with.as_ref() {
+                emit_error!(from_with.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(codec) = self.hint.codec.as_ref() {
+                emit_error!(codec.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(via_adapter) = self.hint.via_adapter.as_ref() {
+                emit_error!(via_adapter.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(via) = self.hint.via.as_ref() {
+                emit_error!(via.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(opt) = self.hint.opt.as_ref() {
+                emit_error!(opt.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(iter) = self.hint.iter.as_ref() {
+                emit_error!(iter.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(map) = self.hint.map.as_ref() {
+                emit_error!(map.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(boxed) = self.hint.boxed.as_ref() {
+                emit_error!(boxed.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(inner) = self.hint.inner.as_ref() {
+                emit_error!(inner.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(r#box) = self.hint.r#box.as_ref() {
+                emit_error!(r#box.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(unbox) = self.hint.unbox.as_ref() {
+                emit_error!(unbox.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(flatten) = self.flatten.as_ref() {
+                emit_error!(flatten.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(from_path) = self.from_path.as_ref() {
+                emit_error!(from_path.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(combine) = self.combine.as_ref() {
+                emit_error!(combine.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(split) = self.split.as_ref() {
+                emit_error!(split.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(index) = self.index.as_ref() {
+                emit_error!(index.span(), "Illegal attribute if 'when' is set")
+            }
+            if let Some(name) = self.name.as_ref() {
+                emit_error!(name.span(), "Illegal attribute if 'when' is set")
             }
             // Verify there same type is not duplicated
             let paths = self.items.iter().map(|i| &i.path).collect::<Vec<_>>();
@@ -449,311 +2548,352 @@ impl VariantReceiver {
                 }
             }
         }
-
-        if let Some(path) = &self.path {
-            ItemVariantInput {
-                path: path.clone(),
-                rename: self.rename.clone(),
-                add: self.add.to_vec(),
-                skip: self.skip.clone(),
-                ignore_extra: self.ignore_extra,
+        // Names are only supported for unnamed fields
+        if self.ident.is_some() {
+            for name in self
+                .name
+                .iter()
+                .chain(self.items.iter().filter_map(|i| i.name.as_ref()))
+            {
+                emit_error!(
+                    name.span(),
+                    "'name' is only supported on unnamed fields, use 'rename' instead"
+                );
             }
-            .validate(derives);
+        }
+        // Flattening, combining and splitting is only supported for named fields
+        if self.ident.is_none() {
+            for flatten in self
+                .flatten
+                .iter()
+                .chain(self.items.iter().filter_map(|i| i.flatten.as_ref()))
+            {
+                emit_error!(flatten.span(), "'flatten' is only supported on named fields");
+            }
+            for combine in self
+                .combine
+                .iter()
+                .chain(self.items.iter().filter_map(|i| i.combine.as_ref()))
+            {
+                emit_error!(combine.span(), "'combine' is only supported on named fields");
+            }
+            for split in self
+                .split
+                .iter()
+                .chain(self.items.iter().filter_map(|i| i.split.as_ref()))
+            {
+                emit_error!(split.span(), "'split' is only supported on named fields");
+            }
+        }
+        // Composed derives just rely on the intermediate type conversions, so no attribute can be set for them
+        let all_via = derives.iter().all(|d| d.via.is_some());
+        for derive in derives.iter().filter(|d| d.via.is_some()) {
+            let path = derive.path.as_ref();
+            let item = if let Some(item) = self.items.iter().find(|i| i.path.as_ref() == path) {
+                Some(item.clone())
+            } else if self.path.as_ref().is_some_and(|p| p.as_ref() == path) || (self.path.is_none() && all_via) {
+                Some(self.root_item(derive.path.clone()))
+            } else {
+                None
+            };
+            for span in item.iter().flat_map(|i| i.attribute_spans()) {
+                emit_error!(span, "Illegal attribute when 'via' is set");
+            }
+        }
+        let span = self.ident.as_ref().map(|i| i.span()).unwrap_or_else(|| self.ty.span());
+        if let Some(path) = &self.path {
+            self.root_item(path.clone()).validate(span, derives);
         } else {
             for d in derives {
-                ItemVariantInput {
-                    path: d.path.clone(),
-                    rename: self.rename.clone(),
-                    add: self.add.to_vec(),
-                    skip: self.skip.clone(),
-                    ignore_extra: self.ignore_extra,
-                }
-                .validate(derives);
+                self.root_item(d.path.clone()).validate(span, derives);
             }
         }
         for item in self.items.iter() {
-            item.validate(derives);
+            item.validate(span, derives);
         }
+    }
 
-        self.fields.iter().for_each(|f| f.validate(derives));
+    /// The root-level attributes of the field, for the given derive path
+    fn root_item(&self, path: SpannedValue<DeriveTy>) -> ItemFieldInput {
+        ItemFieldInput {
+            path,
+            rename: self.rename.clone(),
+            skip: self.skip.clone(),
+            other_ty: self.other_ty.clone(),
+            flatten: self.flatten.clone(),
+            getter: self.getter.clone(),
+            setter: self.setter.clone(),
+            index: self.index,
+            name: self.name.clone(),
+            from_path: self.from_path.clone(),
+            combine: self.combine.clone(),
+            split: self.split.clone(),
+            source: self.source.clone(),
+            target: self.target.clone(),
+            hint: self.hint.clone(),
+        }
+    }
+
+    pub(super) fn rename_for(&self, derive_path: &DeriveTy) -> Option<&syn::Ident> {
+        let rename = if let Some(item) = self.items.iter().find(|i| i.path.as_ref() == derive_path) {
+            item.rename.as_deref()
+        } else if self.path.as_ref().is_none_or(|p| p.as_ref() == derive_path) {
+            self.rename.as_deref()
+        } else {
+            None
+        };
+        // Explicit renames take precedence over the rename rules
+        rename.or_else(|| {
+            self.renamed
+                .iter()
+                .find(|(path, _)| path == derive_path)
+                .map(|(_, renamed)| renamed)
+        })
+    }
+
+    /// Computes the name on the other type of the given derive following its rename rules, if any
+    pub(super) fn apply_rename_rules(&mut self, derive: &ItemInput) {
+        if let Some(ident) = self.ident.as_ref()
+            && let Some(renamed) = derive.rename_rules.rename(ident)
+        {
+            self.renamed.push((derive.path.as_ref().clone(), renamed));
+        }
     }
 
-    pub(super) fn rename_for(&self, derive_path: &syn::TypePath) -> Option<&syn::Ident> {
+    pub(super) fn skip_for(&self, derive_path: &DeriveTy) -> Option<&Override<SkipInput>> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
-                return item.rename.as_deref();
+                return item.skip.as_deref();
             }
         }
         if let Some(path) = &self.path {
             if path.as_ref() == derive_path {
-                self.rename.as_deref()
+                self.skip.as_deref()
             } else {
                 None
             }
         } else {
-            self.rename.as_deref()
+            self.skip.as_deref()
         }
     }
 
-    pub(super) fn additional_for(&self, derive_path: &syn::TypePath) -> Option<&Vec<AddInput>> {
+    pub(super) fn other_ty_for(&self, derive_path: &DeriveTy) -> Option<&syn::Ident> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
-                if item.add.is_empty() {
-                    return None;
-                } else {
-                    return Some(item.add.as_ref());
-                }
+                return item.other_ty.as_deref();
             }
         }
         if let Some(path) = &self.path {
             if path.as_ref() == derive_path {
-                if self.add.is_empty() {
-                    None
-                } else {
-                    Some(self.add.as_ref())
-                }
+                self.other_ty.as_deref()
             } else {
                 None
             }
-        } else if self.add.is_empty() {
-            None
         } else {
-            Some(self.add.as_ref())
+            self.other_ty.as_deref()
         }
     }
 
-    pub(super) fn skip_for(&self, derive_path: &syn::TypePath) -> Option<&Override<SkipInput>> {
+    fn combine_for(&self, derive_path: &DeriveTy) -> Option<&CombineInput> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
-                return item.skip.as_deref();
+                return item.combine.as_deref();
             }
         }
         if let Some(path) = &self.path {
             if path.as_ref() == derive_path {
-                self.skip.as_deref()
+                self.combine.as_deref()
+            } else {
+                None
+            }
+        } else {
+            self.combine.as_deref()
+        }
+    }
+
+    fn split_for(&self, derive_path: &DeriveTy) -> Option<&SplitInput> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.split.as_deref();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.split.as_deref()
+            } else {
+                None
+            }
+        } else {
+            self.split.as_deref()
+        }
+    }
+
+    fn source_for(&self, derive_path: &DeriveTy) -> Option<&TypePathWrapper> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.source.as_deref();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.source.as_deref()
+            } else {
+                None
+            }
+        } else {
+            self.source.as_deref()
+        }
+    }
+
+    fn target_for(&self, derive_path: &DeriveTy) -> Option<&TypePathList> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.target.as_deref();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.target.as_deref()
+            } else {
+                None
+            }
+        } else {
+            self.target.as_deref()
+        }
+    }
+
+    /// Indexes of the parts of the other type this field is mapped into, when deriving into many types (defaults to
+    /// the first)
+    pub(super) fn targets_for(&self, derive_path: &DeriveTy) -> Vec<usize> {
+        let parts = derive_path.parts();
+        let targets = self
+            .target_for(derive_path)
+            .map(|targets| {
+                targets
+                    .0
+                    .iter()
+                    .filter_map(|target| parts.iter().position(|p| *p == target.as_ref()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if targets.is_empty() { vec![0] } else { targets }
+    }
+
+    /// Index of the part of the other type this field belongs to, when deriving from many types (defaults to the first)
+    pub(super) fn part_for(&self, derive_path: &DeriveTy) -> usize {
+        self.source_for(derive_path)
+            .and_then(|source| derive_path.parts().iter().position(|p| *p == source.as_ref()))
+            .unwrap_or_default()
+    }
+
+    pub(super) fn getter_for(&self, derive_path: &DeriveTy) -> Option<&GetterInput> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.getter.as_deref();
+            }
+        }
+        if let Some(path) = &self.path {
+            if path.as_ref() == derive_path {
+                self.getter.as_deref()
             } else {
                 None
             }
         } else {
-            self.skip.as_deref()
+            self.getter.as_deref()
         }
     }
 
-    pub(super) fn ignore_extra_for(&self, derive_path: &syn::TypePath) -> bool {
+    pub(super) fn index_for(&self, derive_path: &DeriveTy) -> Option<usize> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
-                return item.ignore_extra.is_present();
+                return item.index.as_deref().copied();
             }
         }
         if let Some(path) = &self.path {
             if path.as_ref() == derive_path {
-                self.ignore_extra.is_present()
+                self.index.as_deref().copied()
             } else {
-                false
+                None
             }
         } else {
-            self.ignore_extra.is_present()
+            self.index.as_deref().copied()
         }
     }
-}
 
-impl ItemFieldInput {
-    fn validate(&self, span: Span, derives: &[ItemInput]) {
-        let derive = derives.iter().find(|d| d.path.as_ref() == self.path.as_ref());
-        if let Some(derive) = derive {
-            // If there are skipped fields without a default value
-            if let Some(skip) = self.skip.as_ref()
-                && Override::as_ref(skip)
-                    .explicit()
-                    .map(|e| e.default.is_none())
-                    .unwrap_or(true)
-            {
-                // from and try_from must have the custom flag if enabled
-                let mut missing_custom = false;
-                if let Some(from) = derive.from.as_deref()
-                    && from.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true)
-                {
-                    missing_custom = true;
-                }
-                if let Some(try_from) = derive.try_from.as_deref()
-                    && try_from.as_ref().explicit().map(|e| e.custom.is_none()).unwrap_or(true)
-                {
-                    missing_custom = true;
-                }
-                if missing_custom {
-                    emit_error!(
-                        skip.span(),
-                        "Enable `default` here or include `custom` on `from` and `try_from` derives"
-                    );
-                }
-            }
-            // validate only one hint
-            let mut hint_count = 0;
-            if self.hint.with.is_some() {
-                hint_count += 1;
-            }
-            if self.hint.from_with.is_some() || self.hint.into_with.is_some() {
-                hint_count += 1;
-            }
-            if self.hint.opt.is_some() {
-                hint_count += 1;
-            }
-            if self.hint.iter.is_some() {
-                hint_count += 1;
-            }
-            if self.hint.map.is_some() {
-                hint_count += 1;
-            }
-            if self.hint.boxed.is_some() {
-                hint_count += 1;
-            }
-            if self.hint.r#box.is_some() {
-                hint_count += 1;
-            }
-            if self.hint.unbox.is_some() {
-                hint_count += 1;
-            }
-            if hint_count > 1 {
-                emit_error!(
-                    span,
-                    "Only one of 'with', 'into_with'/'from_with', 'opt', 'iter', 'map', 'boxed', 'box' or 'unbox' can \
-                     be set"
-                );
-            }
-        } else {
-            emit_error!(self.path.span(), "There is no derive defined for this type");
-        }
-    }
-}
-impl FieldReceiver {
-    pub(super) fn validate(&self, derives: &[ItemInput]) {
-        if !self.items.is_empty() {
-            // If there are multiple derives, the root-level fields are not allowed
-            if let Some(path) = self.path.as_ref() {
-                emit_error!(path.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(rename) = self.rename.as_ref() {
-                emit_error!(rename.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(skip) = self.skip.as_ref() {
-                emit_error!(skip.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(with) = self.hint.with.as_ref() {
-                emit_error!(with.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(into_with) = self.hint.into_with.as_ref() {
-                emit_error!(into_with.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(from_with) = self.hint.from_with.as_ref() {
-                emit_error!(from_with.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(opt) = self.hint.opt.as_ref() {
-                emit_error!(opt.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(iter) = self.hint.iter.as_ref() {
-                emit_error!(iter.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(map) = self.hint.map.as_ref() {
-                emit_error!(map.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(boxed) = self.hint.boxed.as_ref() {
-                emit_error!(boxed.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(r#box) = self.hint.r#box.as_ref() {
-                emit_error!(r#box.span(), "Illegal attribute if 'when' is set")
-            }
-            if let Some(unbox) = self.hint.unbox.as_ref() {
-                emit_error!(unbox.span(), "Illegal attribute if 'when' is set")
-            }
-            // Verify there same type is not duplicated
-            let paths = self.items.iter().map(|i| &i.path).collect::<Vec<_>>();
-            for i in 0..paths.len() {
-                for j in 0..paths.len() {
-                    if i != j && paths[i].as_ref() == paths[j].as_ref() {
-                        emit_error!(paths[j].span(), "This type is duplicated")
-                    }
-                }
+    pub(super) fn name_for(&self, derive_path: &DeriveTy) -> Option<&syn::Ident> {
+        for item in &self.items {
+            if item.path.as_ref() == derive_path {
+                return item.name.as_deref();
             }
         }
-        let span = self.ident.as_ref().map(|i| i.span()).unwrap_or_else(|| self.ty.span());
         if let Some(path) = &self.path {
-            ItemFieldInput {
-                path: path.clone(),
-                rename: self.rename.clone(),
-                skip: self.skip.clone(),
-                other_ty: self.other_ty.clone(),
-                hint: self.hint.clone(),
+            if path.as_ref() == derive_path {
+                self.name.as_deref()
+            } else {
+                None
             }
-            .validate(span, derives);
         } else {
-            for d in derives {
-                ItemFieldInput {
-                    path: d.path.clone(),
-                    rename: self.rename.clone(),
-                    skip: self.skip.clone(),
-                    other_ty: self.other_ty.clone(),
-                    hint: self.hint.clone(),
-                }
-                .validate(span, derives);
-            }
-        }
-        for item in self.items.iter() {
-            item.validate(span, derives);
+            self.name.as_deref()
         }
     }
 
-    pub(super) fn rename_for(&self, derive_path: &syn::TypePath) -> Option<&syn::Ident> {
+    pub(super) fn setter_for(&self, derive_path: &DeriveTy) -> Option<&syn::Ident> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
-                return item.rename.as_deref();
+                return item.setter.as_deref();
             }
         }
         if let Some(path) = &self.path {
             if path.as_ref() == derive_path {
-                self.rename.as_deref()
+                self.setter.as_deref()
             } else {
                 None
             }
         } else {
-            self.rename.as_deref()
+            self.setter.as_deref()
         }
     }
 
-    pub(super) fn skip_for(&self, derive_path: &syn::TypePath) -> Option<&Override<SkipInput>> {
+    /// Whether this field is an `Option` whose inner value shall be mapped
+    pub(super) fn is_opt_for(&self, derive_path: &DeriveTy) -> bool {
+        self.hint_for(derive_path).is_some_and(|h| h.opt.is_some())
+    }
+
+    fn source_path_for(&self, derive_path: &DeriveTy) -> Option<&FromPathInput> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
-                return item.skip.as_deref();
+                return item.from_path.as_deref();
             }
         }
         if let Some(path) = &self.path {
             if path.as_ref() == derive_path {
-                self.skip.as_deref()
+                self.from_path.as_deref()
             } else {
                 None
             }
         } else {
-            self.skip.as_deref()
+            self.from_path.as_deref()
         }
     }
 
-    pub(super) fn other_ty_for(&self, derive_path: &syn::TypePath) -> Option<&syn::Ident> {
+    fn flatten_for(&self, derive_path: &DeriveTy) -> Option<&FlattenInput> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
-                return item.other_ty.as_deref();
+                return item.flatten.as_deref();
             }
         }
         if let Some(path) = &self.path {
             if path.as_ref() == derive_path {
-                self.other_ty.as_deref()
+                self.flatten.as_deref()
             } else {
                 None
             }
         } else {
-            self.other_ty.as_deref()
+            self.flatten.as_deref()
         }
     }
 
-    fn hint_for(&self, derive_path: &syn::TypePath) -> Option<&MapperHint> {
+    fn hint_for(&self, derive_path: &DeriveTy) -> Option<&MapperHint> {
         for item in &self.items {
             if item.path.as_ref() == derive_path {
                 return Some(&item.hint);
@@ -775,27 +2915,270 @@ impl FieldReceiver {
         from: bool,
         is_try: bool,
         ident: &syn::Ident,
-        derive_path: &syn::TypePath,
+        derive: &ItemInput,
     ) -> TokenStream {
-        let into = build_into_for_inner(from, is_try, ident, self.hint_for(derive_path));
+        let into = build_into_for_inner(
+            from,
+            is_try,
+            ident,
+            self.hint_for(derive.path.as_ref()),
+            Some(&self.ty),
+            &derive.convert,
+        );
         if is_try { quote!(#into?) } else { into }
     }
+
+    /// The member of the other type this field is mapped from or into: the explicit index or name, the renamed ident or
+    /// the same ident (or position, for unnamed fields)
+    pub(super) fn other_member_for(&self, derive_path: &DeriveTy, ix: usize) -> TokenStream {
+        if let Some(index) = self.index_for(derive_path) {
+            syn::Index::from(index).to_token_stream()
+        } else if let Some(name) = self.name_for(derive_path) {
+            name.to_token_stream()
+        } else if let Some(rename) = self.rename_for(derive_path) {
+            rename.to_token_stream()
+        } else {
+            self.self_member(ix)
+        }
+    }
+
+    /// The member of self for this field: its ident or its position, for unnamed fields
+    pub(super) fn self_member(&self, ix: usize) -> TokenStream {
+        match &self.ident {
+            Some(ident) => ident.to_token_stream(),
+            None => syn::Index::from(ix).to_token_stream(),
+        }
+    }
+
+    /// Whether this field is mapped to a single field of the other type with the same (or renamed) name, on the given
+    /// direction
+    pub(super) fn maps_directly_for(&self, from: bool, derive_path: &DeriveTy) -> bool {
+        self.skip_for(derive_path).is_none()
+            && self.flatten_for(derive_path).is_none()
+            && self.source_path_for(derive_path).is_none()
+            && if from {
+                self.combine_for(derive_path).is_none()
+            } else {
+                self.split_for(derive_path).is_none()
+            }
+    }
+
+    /// Retrieves the fields of the other type combined into this one, along with the ident they're bound to
+    pub(super) fn combined_fields_for(&self, derive_path: &DeriveTy) -> Vec<(&syn::Ident, syn::Ident)> {
+        let (Some(ident), Some(combine)) = (self.ident.as_ref(), self.combine_for(derive_path)) else {
+            return Vec::new();
+        };
+        combine
+            .fields
+            .0
+            .iter()
+            .map(|other| (other, field_binding(ident, other)))
+            .collect()
+    }
+
+    /// Builds the value of this field by combining many fields of the other type
+    pub(super) fn build_combine(&self, is_try: bool, derive_path: &DeriveTy) -> TokenStream {
+        let Some(combine) = self.combine_for(derive_path) else {
+            return TokenStream::new();
+        };
+        let with = &combine.with;
+        let bindings = self
+            .combined_fields_for(derive_path)
+            .into_iter()
+            .map(|(_, binding)| binding);
+        if is_try {
+            quote!((#with)( #( #bindings ),* )?)
+        } else {
+            quote!((#with)( #( #bindings ),* ))
+        }
+    }
+
+    /// Retrieves the fields of the other type this one is split into, along with the ident they're bound to
+    pub(super) fn split_fields_for(&self, derive_path: &DeriveTy) -> Vec<(&syn::Ident, syn::Ident)> {
+        let (Some(ident), Some(split)) = (self.ident.as_ref(), self.split_for(derive_path)) else {
+            return Vec::new();
+        };
+        split
+            .into
+            .0
+            .iter()
+            .map(|other| (other, field_binding(ident, other)))
+            .collect()
+    }
+
+    /// Builds the statement to split this field into the bindings of many fields of the other type
+    pub(super) fn build_split(&self, is_try: bool, derive_path: &DeriveTy) -> TokenStream {
+        let (Some(ident), Some(split)) = (self.ident.as_ref(), self.split_for(derive_path)) else {
+            return TokenStream::new();
+        };
+        let with = &split.with;
+        let bindings = self
+            .split_fields_for(derive_path)
+            .into_iter()
+            .map(|(_, binding)| binding);
+        if is_try {
+            quote!(let ( #( #bindings, )* ) = (#with)(#ident)?;)
+        } else {
+            quote!(let ( #( #bindings, )* ) = (#with)(#ident);)
+        }
+    }
+
+    /// Retrieves the field of the other type this one is mapped from, if it's mapped from a nested field
+    pub(super) fn source_root_for(&self, derive_path: &DeriveTy) -> Option<&syn::Ident> {
+        self.source_path_for(derive_path).map(|p| &p.root)
+    }
+
+    /// Builds the statements unwrapping the optional values along the nested field of the other type this one is
+    /// mapped from, along with the path they unwrap
+    pub(super) fn build_from_path_unwraps(&self, derive_path: &DeriveTy) -> Vec<(String, TokenStream)> {
+        self.source_path_for(derive_path)
+            .map(|p| p.build().0)
+            .unwrap_or_default()
+    }
+
+    /// Builds the value of this field from a nested field of the other type, once its optional values are unwrapped
+    pub(super) fn build_from_path(&self, is_try: bool, ident: &syn::Ident, derive: &ItemInput) -> TokenStream {
+        let Some(from_path) = self.source_path_for(derive.path.as_ref()) else {
+            return TokenStream::new();
+        };
+        let (_, accessed) = from_path.build();
+        let into = self.build_into_for(true, is_try, ident, derive);
+        quote!({
+            let #ident = #accessed;
+            #into
+        })
+    }
+
+    /// Whether this field is flattened into the other type
+    pub(super) fn is_flattened_for(&self, derive_path: &DeriveTy) -> bool {
+        self.flatten_for(derive_path).is_some()
+    }
+
+    /// Retrieves the fields of the other type this one is flattened into, along with the ident they're bound to
+    pub(super) fn flattened_fields_for(&self, derive_path: &DeriveTy) -> Vec<(&syn::Ident, syn::Ident)> {
+        let (Some(ident), Some(flatten)) = (self.ident.as_ref(), self.flatten_for(derive_path)) else {
+            return Vec::new();
+        };
+        flatten
+            .fields
+            .iter()
+            .map(|(field, other)| (other, field_binding(ident, field)))
+            .collect()
+    }
+
+    /// Builds the nested struct of a flattened field, from the bound fields of the other type
+    pub(super) fn build_flatten_from(&self, is_try: bool, derive_path: &DeriveTy) -> TokenStream {
+        let (Some(ident), Some(flatten)) = (self.ident.as_ref(), self.flatten_for(derive_path)) else {
+            return TokenStream::new();
+        };
+        let nested_ty = self.flattened_ty(flatten);
+        let bindings = flatten
+            .fields
+            .iter()
+            .map(|(field, _)| field_binding(ident, field))
+            .collect::<Vec<_>>();
+        let fields = flatten.fields.iter().map(|(field, _)| field);
+        let values = bindings.iter().map(|binding| {
+            if is_try {
+                quote!(TryInto::try_into(#binding)?)
+            } else {
+                quote!(Into::into(#binding))
+            }
+        });
+        let nested = quote!(#nested_ty { #( #fields: #values ),* });
+        if flatten.opt && is_try {
+            // The nested struct is only present if every field is, failing if just some of them are
+            let nones = bindings.iter().map(|_| quote!(None));
+            let msg = format!("Expected either all or none of the fields flattened into '{ident}'");
+            quote!(match ( #( #bindings, )* ) {
+                ( #( Some(#bindings), )* ) => Some(#nested),
+                ( #( #nones, )* ) => None,
+                _ => return Err(::anyhow::anyhow!(#msg)),
+            })
+        } else if flatten.opt {
+            // The nested struct is only present if every field is
+            quote!(match ( #( #bindings, )* ) {
+                ( #( Some(#bindings), )* ) => Some(#nested),
+                _ => None,
+            })
+        } else {
+            nested
+        }
+    }
+
+    /// Builds the statement to bind the fields of a flattened field, to be used to build the other type
+    pub(super) fn build_flatten_into(&self, is_try: bool, derive_path: &DeriveTy) -> TokenStream {
+        let (Some(ident), Some(flatten)) = (self.ident.as_ref(), self.flatten_for(derive_path)) else {
+            return TokenStream::new();
+        };
+        let nested_ty = self.flattened_ty(flatten);
+        let fields = flatten.fields.iter().map(|(field, _)| field).collect::<Vec<_>>();
+        let bindings = flatten
+            .fields
+            .iter()
+            .map(|(field, _)| field_binding(ident, field))
+            .collect::<Vec<_>>();
+        let values = bindings
+            .iter()
+            .map(|binding| {
+                if is_try {
+                    quote!(TryInto::try_into(#binding)?)
+                } else {
+                    quote!(Into::into(#binding))
+                }
+            })
+            .collect::<Vec<_>>();
+        if flatten.opt {
+            // Every field is present only if the nested struct is
+            let nones = bindings.iter().map(|_| quote!(None));
+            quote!(
+                let ( #( #bindings, )* ) = match #ident {
+                    Some(#nested_ty { #( #fields: #bindings, )* }) => ( #( Some(#values), )* ),
+                    None => ( #( #nones, )* ),
+                };
+            )
+        } else {
+            quote!(
+                let #nested_ty { #( #fields: #bindings, )* } = #ident;
+                let ( #( #bindings, )* ) = ( #( #values, )* );
+            )
+        }
+    }
+
+    /// Retrieves the path of the nested struct of a flattened field, without generics
+    fn flattened_ty(&self, flatten: &FlattenInput) -> TokenStream {
+        let ty = if flatten.opt {
+            type_argument(&self.ty, Some(0))
+        } else {
+            Some(&self.ty)
+        };
+        if let Some(syn::Type::Path(ty)) = ty {
+            let mut ty = ty.clone();
+            for segment in &mut ty.path.segments {
+                segment.arguments = syn::PathArguments::None;
+            }
+            quote!(#ty)
+        } else {
+            emit_error!(self.ty.span(), "Flattened fields must be structs");
+            TokenStream::new()
+        }
+    }
 }
 
-fn build_into_for_inner(from: bool, is_try: bool, ident: &syn::Ident, hint: Option<&MapperHint>) -> TokenStream {
+fn build_into_for_inner(
+    from: bool,
+    is_try: bool,
+    ident: &syn::Ident,
+    hint: Option<&MapperHint>,
+    ty: Option<&syn::Type>,
+    converts: &[ConvertInput],
+) -> TokenStream {
     if let Some(hint) = hint {
         let check_with = |with: &Option<SpannedValue<syn::Expr>>| {
             if let Some(with) = with {
                 let with = with.as_ref();
                 if let syn::Expr::Path(with_path) = with {
-                    let crate_name = match crate_name("model-mapper") {
-                        Ok(FoundCrate::Itself) => quote!(::model_mapper),
-                        Ok(FoundCrate::Name(name)) => {
-                            let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
-                            quote!(::#ident)
-                        }
-                        Err(_) => quote!(::model_mapper),
-                    };
+                    let crate_name = model_mapper_crate();
                     Some(quote!({
                         use #crate_name::private::{RefMapper, ValueMapper};
                         (&(#with_path)).map_value(#ident)
@@ -820,102 +3203,182 @@ fn build_into_for_inner(from: bool, is_try: bool, ident: &syn::Ident, hint: Opti
 
         if let Some(t) = check_with(&hint.with) {
             return t;
-        } else if let Some(opt) = &hint.opt {
-            let inner;
-            if let Some(inner_hint) = opt.as_ref().as_ref().explicit() {
-                inner = build_into_for_inner(from, is_try, ident, Some(inner_hint));
+        } else if let Some(codec) = &hint.codec {
+            let crate_name = model_mapper_crate();
+            // The codec encodes self values into the other type and decodes them back
+            let func = match (from, is_try) {
+                (true, false) => format_ident!("decode"),
+                (false, false) => format_ident!("encode"),
+                (true, true) => format_ident!("try_decode"),
+                (false, true) => format_ident!("try_encode"),
+            };
+            let codec = codec.as_ref();
+            return quote!({
+                #[allow(unused_imports)]
+                use #crate_name::{Codec as _, TryCodec as _};
+                #codec::#func(#ident)
+            });
+        } else if let Some(adapter) = &hint.via_adapter {
+            let crate_name = model_mapper_crate();
+            let adapter = adapter.as_ref();
+            if is_try {
+                return quote!(<#adapter as #crate_name::TryAdapter<_, _>>::try_adapt(#ident));
             } else {
-                inner = build_into_for_inner(from, is_try, ident, None);
+                return quote!(<#adapter as #crate_name::Adapter<_, _>>::adapt(#ident));
             }
+        } else if let Some(via) = &hint.via {
+            let via_ty = &via.ty;
+            if via.fallible {
+                if !is_try {
+                    emit_error!(
+                        via.span(),
+                        "Fallible 'via' is only supported on 'try_from' and 'try_into' derives"
+                    );
+                }
+                return quote!(
+                    TryInto::<#via_ty>::try_into(#ident)
+                        .map_err(::anyhow::Error::from)
+                        .and_then(|#ident| TryInto::try_into(#ident).map_err(::anyhow::Error::from))
+                );
+            } else if is_try {
+                return quote!(Ok::<_, ::anyhow::Error>(Into::into(Into::<#via_ty>::into(#ident))));
+            } else {
+                return quote!(Into::into(Into::<#via_ty>::into(#ident)));
+            }
+        } else if let Some(container) = hint.opt.as_ref().or(hint.boxed.as_ref()).or(hint.inner.as_ref()) {
+            // Containers preserving its shape are mapped with the `MapInner` trait
+            let crate_name = model_mapper_crate();
+            let inner_hint = container.as_ref().as_ref().explicit().map(|h| &**h);
+            // Maps have their values on the last type argument
+            let inner_ty = if hint.inner.is_some() {
+                ty.and_then(|t| type_argument(t, None))
+            } else {
+                ty.and_then(|t| type_argument(t, Some(0)))
+            };
+            let inner = build_into_for_inner(from, is_try, ident, inner_hint, inner_ty, converts);
             if is_try {
-                return quote!(#ident.map(|#ident| #inner).transpose());
+                return quote!(#crate_name::MapInner::try_map_inner(#ident, |#ident| #inner));
             } else {
-                return quote!(#ident.map(|#ident| #inner));
+                return quote!(#crate_name::MapInner::map_inner(#ident, |#ident| #inner));
             }
         } else if let Some(iter) = &hint.iter {
-            let inner;
-            if let Some(inner_hint) = iter.as_ref().as_ref().explicit() {
-                inner = build_into_for_inner(from, is_try, ident, Some(inner_hint));
-            } else {
-                inner = build_into_for_inner(from, is_try, ident, None);
-            }
+            let crate_name = model_mapper_crate();
+            let inner_hint = iter.as_ref().as_ref().explicit().map(|h| &**h);
+            let inner_ty = ty.and_then(|t| type_argument(t, Some(0)));
+            let inner = build_into_for_inner(from, is_try, ident, inner_hint, inner_ty, converts);
             if is_try {
-                return quote!(#ident.into_iter().map(|#ident| #inner).collect::<std::result::Result<_, _>>());
+                return quote!(#crate_name::CollectInner::try_collect_inner(#ident, |#ident| #inner));
             } else {
-                return quote!(#ident.into_iter().map(|#ident| #inner).collect());
+                return quote!(#crate_name::CollectInner::collect_inner(#ident, |#ident| #inner));
             }
         } else if let Some(map) = &hint.map {
-            let inner;
-            if let Some(inner_hint) = map.as_ref().as_ref().explicit() {
-                inner = build_into_for_inner(from, is_try, ident, Some(inner_hint));
-            } else {
-                inner = build_into_for_inner(from, is_try, ident, None);
-            }
+            let crate_name = model_mapper_crate();
+            let inner_hint = map.as_ref().as_ref().explicit().map(|h| &**h);
+            let inner_ty = ty.and_then(|t| type_argument(t, Some(1)));
+            let inner = build_into_for_inner(from, is_try, ident, inner_hint, inner_ty, converts);
             if is_try {
-                return quote!(
-                    #ident.into_iter().map(|(k, #ident)| #inner.map(|v| (k, v))).collect::<std::result::Result<_, _>>()
-                );
+                return quote!(#crate_name::CollectValues::try_collect_values(#ident, |#ident| #inner));
             } else {
-                return quote!(#ident.into_iter().map(|(k, #ident)| (k, #inner)).collect());
-            }
-        } else if hint.boxed.is_some() || hint.r#box.is_some() || hint.unbox.is_some() {
-            let (is_input_boxed, is_output_boxed) = if hint.boxed.is_some() {
-                (true, true)
-            } else if hint.r#box.is_some() {
-                if from { (true, false) } else { (false, true) }
-            } else {
-                if from { (false, true) } else { (true, false) }
-            };
-
-            let hint_opt = hint
-                .boxed
-                .as_ref()
-                .or(hint.r#box.as_ref())
-                .or(hint.unbox.as_ref())
-                .unwrap();
-            let inner;
-            if let Some(inner_hint) = hint_opt.as_ref().as_ref().explicit() {
-                inner = build_into_for_inner(from, is_try, ident, Some(inner_hint));
-            } else {
-                inner = build_into_for_inner(from, is_try, ident, None);
-            }
-
-            let input_expr = if is_input_boxed {
-                quote!(*#ident)
+                return quote!(#crate_name::CollectValues::collect_values(#ident, |#ident| #inner));
+            }
+        } else if let Some(boxing) = hint.r#box.as_ref().or(hint.unbox.as_ref()) {
+            // Whether the input or the output is the boxed one
+            let is_output_boxed = hint.r#box.is_some() != from;
+            let inner_hint = boxing.as_ref().as_ref().explicit().map(|h| &**h);
+            // Only the `unbox` hint has self field boxed
+            let inner_ty = if hint.unbox.is_some() {
+                ty.and_then(|t| type_argument(t, Some(0)))
             } else {
-                quote!(#ident)
+                ty
             };
-
-            if is_try {
-                if is_output_boxed {
-                    return quote!({
-                        let #ident = #input_expr;
-                        #inner.map(|v| std::boxed::Box::new(v))
-                    });
+            let inner = build_into_for_inner(from, is_try, ident, inner_hint, inner_ty, converts);
+            if is_output_boxed {
+                if is_try {
+                    return quote!(#inner.map(::std::boxed::Box::new));
                 } else {
-                    return quote!({
-                        let #ident = #input_expr;
-                        #inner
-                    });
+                    return quote!(::std::boxed::Box::new(#inner));
                 }
             } else {
-                if is_output_boxed {
-                    return quote!({
-                        let #ident = #input_expr;
-                        std::boxed::Box::new(#inner)
-                    });
-                } else {
-                    return quote!({
-                        let #ident = #input_expr;
-                        #inner
-                    });
-                }
+                return quote!({
+                    let #ident = *#ident;
+                    #inner
+                });
             }
         }
     }
+    // Look for a type-level conversion matching the self type, if no other hint was provided
+    if let Some(syn::Type::Path(ty)) = ty
+        && let Some(convert) = converts.iter().filter(|c| c.applies_to(from)).find(|c| {
+            if from {
+                c.dst.as_ref() == ty
+            } else {
+                c.src.as_ref() == ty
+            }
+        })
+    {
+        let crate_name = model_mapper_crate();
+        let with = convert.with.as_ref();
+        return quote!({
+            use #crate_name::private::{RefMapper, ValueMapper};
+            (&(#with)).map_value(#ident)
+        });
+    }
     if is_try {
         quote!(TryInto::try_into(#ident))
     } else {
         quote!(Into::into(#ident))
     }
 }
+
+/// Emits an error for a type referenced as one of the types of the derive while being an element of a quoted tuple,
+/// which is a plain tuple type rather than a tuple of types
+fn emit_quoted_tuple_error(span: Span, ty: &syn::TypePath) {
+    emit_error!(
+        span,
+        "'{}' is an element of a plain tuple type, not one of the types of the derive",
+        ty.to_token_stream();
+        help = "Remove the quotes around the tuple to derive each of its types, like `ty = (User, Profile)`"
+    );
+}
+
+/// The ident a field bound on behalf of another one (flattened, combined, split or cloned for many parts) is bound to,
+/// prefixed to avoid clashing with the fields of self
+pub(super) fn field_binding(ident: &syn::Ident, field: &syn::Ident) -> syn::Ident {
+    format_ident!("__{ident}_{field}")
+}
+
+/// Retrieves the type argument at the given position (or the last one) of a generic type
+fn type_argument(ty: &syn::Type, position: Option<usize>) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Path(ty) => {
+            let syn::PathArguments::AngleBracketed(args) = &ty.path.segments.last()?.arguments else {
+                return None;
+            };
+            let mut types = args.args.iter().filter_map(|a| match a {
+                syn::GenericArgument::Type(t) => Some(t),
+                _ => None,
+            });
+            match position {
+                Some(position) => types.nth(position),
+                None => types.last(),
+            }
+        }
+        syn::Type::Array(ty) => Some(&ty.elem),
+        syn::Type::Slice(ty) => Some(&ty.elem),
+        syn::Type::Paren(ty) => type_argument(&ty.elem, position),
+        syn::Type::Group(ty) => type_argument(&ty.elem, position),
+        _ => None,
+    }
+}
+
+/// Retrieves the path to the `model_mapper` crate, as it might have been renamed
+pub(super) fn model_mapper_crate() -> TokenStream {
+    match crate_name("model-mapper") {
+        Ok(FoundCrate::Itself) => quote!(::model_mapper),
+        Ok(FoundCrate::Name(name)) => {
+            let ident = syn::Ident::new(&name, proc_macro2::Span::call_site());
+            quote!(::#ident)
+        }
+        Err(_) => quote!(::model_mapper),
+    }
+}
@@ -15,18 +15,45 @@ use proc_macro_error2::proc_macro_error;
 ///
 /// - `ty = PathType` _(**mandatory**)_: The other type to derive the conversion. Can be a string literal for complex
 ///   types (e.g. `ty = "Type<T>"`)
+///   - `ty = (User, Profile)` _(optional)_: A tuple of types can be provided to derive all of them at once,
+///     destructuring each one. Along with the trait, a `from_parts` (or `try_from_parts`) function receiving every part
+///     is derived for `from` and `try_from`, and an `into_parts` (or `try_into_parts`) function returning every part
+///     for `into` and `try_into`. Just the function is derived if `custom` is set. Generic types are not supported as
+///     parts
+///   - `ty = "(String, i64)"` _(optional)_: A plain tuple type, quoted to tell it apart from a tuple of types. The
+///     fields mapped directly are mapped by position, in the order they are declared unless an `index` is provided.
+///     Elements not mapped by any field fail to compile, unless `ignore_extra` or `fill_default` is set
 /// - `from` _(optional)_: Whether to derive `From` the other type for self
 ///   - `custom` _(optional)_: Derive a custom function instead of the trait
 ///   - `custom = from_other` _(optional)_: Derive a custom function instead of the trait, with the given name
+///   - `getters` _(optional)_: Read the fields of the other type through its getter methods (named after each field)
+///     instead of deconstructing it, for types with private fields
 /// - `into` _(optional)_: Whether to derive `From` self for the other type
 ///   - `custom` _(optional)_: Derive a custom function instead of the trait
 ///   - `custom = from_other` _(optional)_: Derive a custom function instead of the trait, with the given name
+///   - `constructor = Other::new(field_a, field_b)` _(optional)_: Build the other type calling the given function
+///     instead of a struct literal, for types with private fields or invariants. Every field of the other type (mapped,
+///     flattened, split or additional) is passed as an argument by its name, after applying the hints
+///   - `builder = Other::builder` _(optional)_: Build the other type through its builder instead of a struct literal,
+///     calling one setter per field (named after it) on the value returned by the given function (or expression).
+///     Builders whose setters take `&mut self` must be provided by reference, like `builder = &mut Other::builder()`
+///   - `build = build` _(optional)_: The method finishing the builder, defaults to `build`
+///   - `params_builder` _(optional)_: Along with `custom`, provide the additional fields through a staged builder
+///     instead of the function parameters, like `bar.into_foo().field3(1).field4(None).finish()`. Calling `finish`
+///     before setting every one of them fails to compile. It's not named `builder`, as `builder = ..` already sets the
+///     builder of the other type. Additional fields named `finish`, `__from` or `__marker` must have a default value
 /// - `try_from` _(optional)_: Whether to derive `TryFrom` the other type for self
 ///   - `custom` _(optional)_: Derive a custom function instead of the trait
 ///   - `custom = from_other` _(optional)_: Derive a custom function instead of the trait, with the given name
+///   - `getters` _(optional)_: Read the fields of the other type through its getter methods (named after each field)
+///     instead of deconstructing it, for types with private fields
 /// - `try_into` _(optional)_: Whether to derive `TryFrom` self for the other type
 ///   - `custom` _(optional)_: Derive a custom function instead of the trait
 ///   - `custom = from_other` _(optional)_: Derive a custom function instead of the trait, with the given name
+///   - `constructor = Other::try_new(field_a, field_b)` _(optional)_: The same as above, but the function must return a
+///     `Result`
+///   - `builder = Other::builder` _(optional)_: The same as above, but the build method must return a `Result`
+///   - `params_builder` _(optional)_: The same as above, but `finish` returns a `Result`
 /// - `add` _(optional, multiple)_: Additional fields (for structs with named fields) or variants (for enums) the other
 ///   type has and this one doesn't **&#xb9;**
 ///   - `field = other_field` _(mandatory)_: The field or variant name
@@ -34,8 +61,64 @@ use proc_macro_error2::proc_macro_error;
 ///   - `default` _(optional)_: The field or variant will be populated using `Default::default()` (mandatory for enums,
 ///     with or without value)
 ///     - `value = true` _(optional)_: The field or variant will be populated with the given expression instead
+///   - `source = Profile` _(optional)_: The type the field belongs to when deriving `from` or `try_from` many types at
+///     once, defaults to the first one
+///   - `target = NewProfile` _(optional)_: The type the field belongs to when deriving `into` or `try_into` many types
+///     at once, defaults to the first one
 /// - `ignore_extra` _(optional)_: Whether to ignore all extra fields (for structs) or variants (for enums) of the other
 ///   type **&#xb2;**
+/// - `fill_default` _(optional)_: Whether to populate the unmapped fields of either side with their default value (for
+///   structs). Extra fields of the other type are ignored on `from` and `try_from` derives and populated with
+///   `..Default::default()` on `into` and `try_into` ones, while skipped fields without a default value are populated
+///   with `..Default::default()` of self instead of requiring a `custom` function
+/// - `ignore(field_a, field_b)` _(optional)_: Extra fields (for structs) or variants (for enums) of the other type to
+///   ignore, so any other extra one still fails to compile. Ignored fields are populated with their default value on
+///   `into` and `try_into` derives for structs, while ignored variants are routed to the default value of self on
+///   `from` and `try_from` derives for enums. When deriving many types at once, fields belong to the first one unless
+///   qualified with its type (like `Profile::bio`)
+/// - `rename_all = "camelCase"` _(optional)_: The case convention of the names of every field (for structs) or variant
+///   (for enums) on the other type, one of the `case` values of `derive(str)`. Keywords are mapped into raw
+///   identifiers, and explicit renames take precedence
+/// - `strip_prefix = "Prefix"` _(optional)_: Prefix to remove from the names of self, before converting them
+/// - `add_prefix = "PREFIX_"` _(optional)_: Prefix to add to the names, after converting them
+/// - `add_suffix = "_SUFFIX"` _(optional)_: Suffix to add to the names, after converting them
+/// - `convert` _(optional, multiple)_: Type-directed conversion applied to every field matching the type, unless the
+///   field provides its own `with`. It applies to self fields of type `src` on `into` and `try_into` derives and to
+///   self fields of type `dst` on `from` and `try_from` derives, including inner types when using hints
+///   - `src = DateTime<Utc>` _(mandatory)_: The type of the value to convert
+///   - `dst = i64` _(mandatory)_: The type the value is converted into
+///   - `with = ts_to_millis` _(mandatory)_: The function used to convert the values
+///   - `from` _(optional)_: Only apply the rule on `from` and `try_from` derives. Either `from` or `into` is mandatory
+///     when the derive has both directions, as the type of the other field is unknown
+///   - `into` _(optional)_: Only apply the rule on `into` and `try_into` derives
+/// - `via = DomainModel` _(optional)_: Derive the conversions by composing the existing ones of the intermediate type,
+///   instead of mapping each field. It can't be combined with `custom`, `add`, `ignore_extra` or `convert`, nor with
+///   field attributes targeting this derive
+/// - `variant = UserCreated` _(optional)_: For structs only, the variant of the other enum self is mapped from or into,
+///   building it from the fields of self (unit structs are mapped to unit variants). Any other variant fails on
+///   `try_from` derives, and `from` derives require a `default`. Fields of the variant missing on self must be provided
+///   with `add` on `into` and `try_into` derives, as `ignore_extra` and `fill_default` are not supported there
+///   - `default` _(optional)_: Along with a `variant`, self will be populated using `Default::default()` when deriving
+///     from any other variant
+///     - `value = Self::empty()` _(optional)_: Self will be populated with the given expression instead
+/// - `upgrade_from(V2, V1)` _(optional)_: Previous versions of this type, from the most recent to the oldest one. It
+///   derives `From` every older version by chaining the existing conversions between consecutive versions
+///   - `try` _(optional)_: Derive `TryFrom` instead, chaining `TryFrom` conversions
+///   - `wrapper = AnyConfig` _(optional)_: Generate an enum with a variant for each version (named after the types,
+///     without their common prefix) and an `upgrade` method to convert it into the latest version
+/// - `derive(str)` _(optional)_: For enums with unit variants only, derive `as_str`, `Display`, `FromStr` and
+///   `TryFrom<&str>` mapping every variant to its name (or its `rename`), failing to parse with a `ParseEnumError`. It
+///   can be combined with any other `derive`
+///   - `case = "SCREAMING_SNAKE_CASE"` _(optional)_: The case convention of the variant names, one of `lowercase`,
+///     `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` or
+///     `SCREAMING-KEBAB-CASE`
+///   - `case_insensitive` _(optional)_: Parse the strings ignoring their ASCII case
+/// - `derive(int)` _(optional)_: For C-like enums only, derive `From` self for the integer type and `TryFrom` the
+///   integer type for self, mapping every variant to its discriminant (or its `code`) and failing with a
+///   `ParseEnumError` on unknown values. It can be combined with any other `derive`
+///   - `ty = u8` _(optional)_: The integer type, defaults to `i32`
+///   - `fallback = Unknown` _(optional)_: The variant unknown values are mapped into, deriving `From` instead of
+///     `TryFrom`. If it has a single unnamed field, it keeps the unknown value
 ///
 /// #### Variant level attributes
 ///
@@ -44,6 +127,7 @@ use proc_macro_error2::proc_macro_error;
 ///   doesn't **&#xb9;**
 ///   - `field = other_field` _(mandatory)_: The field name
 ///   - `ty = bool` _(optional)_: The field type, mandatory for `into` and `try_into` if no default value is provided
+///   - `index = 1` _(optional)_: The position of the field, mandatory when the variant `shape` is `tuple`
 ///   - `default` _(optional)_: The field or variant will be populated using `Default::default()`
 ///     - `value = true` _(optional)_: The field or variant will be populated with the given expression instead
 /// - `skip` _(optional)_: Whether to skip this variant because the other enum doesn't have it
@@ -52,6 +136,21 @@ use proc_macro_error2::proc_macro_error;
 ///       instead
 /// - `ignore_extra` _(optional)_: Whether to ignore all extra fields of the other variant (only valid for _from_ and
 ///   _try_from_) **&#xb2;**
+/// - `ignore(field_a, field_b)` _(optional)_: Extra fields of the other variant to ignore, so any other extra one still
+///   fails to compile. They are populated with their default value on `into` and `try_into` derives
+/// - `alias = "other_name"` _(optional, multiple)_: Additional strings the variant is parsed from on `str` derives
+/// - `code = 7` _(optional)_: The integer the variant is mapped to on `int` derives, instead of its discriminant. Every
+///   code must be unique
+/// - `shape = tuple` _(optional)_: The shape of the fields of the other variant (`tuple` or `named`), to reshape them.
+///   Fields are mapped by position for `tuple`, in the order they are declared unless an `index` is provided, and
+///   unnamed fields need a `name` for `named`
+/// - `from_variants = [Pending, Queued]` _(optional)_: The variants of the other enum mapped into this one on `from`
+///   and `try_from` derives, instead of the one with the same (or renamed) name
+/// - `into_variant = Queued` _(optional)_: The variant of the other enum this one is mapped into on `into` and
+///   `try_into` derives, mandatory if it is mapped from many variants not including its own
+/// - `split_by = sides == 3` _(optional)_: A predicate on the fields of the other variant (bound by the names of self)
+///   to tell apart the variants of self mapped from the same one, checked in order. The last of them may omit it to
+///   take any other value, otherwise `try_from` derives fail when no predicate matches
 ///
 /// #### Field level attributes
 ///
@@ -62,6 +161,27 @@ use proc_macro_error2::proc_macro_error;
 ///   - `default` _(optional)_: The field or variant will be populated using `Default::default()`
 ///     - `value = get_default_value()` _(optional)_: The field or variant will be populated with the given expression
 ///       instead
+/// - `flatten(street, city, zip = postal_code)` _(optional)_: The field is a struct whose fields are flattened into
+///   sibling fields of the other type, renaming them if provided. Every field of the struct must be listed. It can't be
+///   combined with `rename` or hints
+///   - `opt` _(optional)_: The field is an `Option` of the struct, flattened into optional fields. It's present only if
+///     every field is, while having just some of them fails on `try_from` derives
+/// - `from_path = profile.contact.email` _(optional)_: The field is mapped from a nested field of the other type on
+///   `from` and `try_from` derives, while it's skipped on `into` and `try_into` ones. On `try_from` derives, optional
+///   values can be unwrapped with `?` (like `profile?.contact?.email`), failing if missing. Each optional value is
+///   unwrapped once, so many fields can share it, but the root field (`profile`) can't be mapped, added or ignored
+///   otherwise
+/// - `combine(fields = [a, b], with = mod::my_function)` _(optional)_: The field is built from many fields of the other
+///   type on `from` and `try_from` derives, by providing a function receiving all of them. Derives also having `into`
+///   or `try_into` require a `split` as well
+/// - `split(into = [a, b], with = mod::my_function)` _(optional)_: The field is split into many fields of the other
+///   type on `into` and `try_into` derives, by providing a function returning a tuple with all of them. Derives also
+///   having `from` or `try_from` require a `combine` as well
+/// - `source = Profile` _(optional)_: The type the field is taken from when deriving `from` or `try_from` many types at
+///   once, defaults to the first one
+/// - `target = NewProfile` _(optional)_: The types the field is mapped into when deriving `into` or `try_into` many
+///   types at once, defaults to the first one. A list like `target = [NewUser, NewProfile]` can be provided to clone
+///   the field into many of them
 ///
 /// Additional hints on how to map fields:
 ///
@@ -69,12 +189,31 @@ use proc_macro_error2::proc_macro_error;
 /// - `iter` _(optional)_: The field is an iterator and the inner value shall be mapped **&#xb3;**
 /// - `map` _(optional)_: The field is a hashmap-like iterator and the inner value shall be mapped **&#xb3;**
 /// - `boxed` _(optional)_: The field is a `Box` and the inner value shall be mapped **&#xb3;**
+/// - `inner` _(optional)_: The field is any container implementing `MapInner` (like `Vec` or `HashMap`) and the inner
+///   value shall be mapped, preserving the container type **&#xb3;**
 /// - `box` _(optional)_: The other field is a `Box` while the current field is not **&#xb3;**
 /// - `unbox` _(optional)_: The current field is a `Box` while the other field is not **&#xb3;**
 /// - `with = mod::my_function` _(optional)_: If the field type doesn't implement `Into` or `TryInto` the other, this
 ///   property allows you to customize the behavior by providing a conversion function
 /// - `from_with = mod::my_function` _(optional)_: The same as above but only for the `from` or `try_from` derives
+/// - `getter = get_name()` _(optional)_: The getter method used to read the field of the other type, on `from` and
+///   `try_from` derives with `getters`. Getters named `into_*` take the other type by value, so they are called after
+///   the rest of them and there can be just one
+/// - `setter = with_name` _(optional)_: The setter method used to set the field on the builder of the other type, on
+///   `into` and `try_into` derives with a `builder`. Optional fields (`opt`) are only set when present
+/// - `index = 2` _(optional)_: The position of the field on the other type, to map named fields from or into tuple
+///   structs, plain tuples or tuple variants, or to reorder the fields of tuple structs and variants
+/// - `name = other_field` _(optional)_: For unnamed fields only, the name of the field on the other type, to map tuple
+///   structs or variants from or into named ones
 /// - `from_with = mod::my_function` _(optional)_: The same as above but only for the `from` or `try_from` derives
+/// - `codec = mod::my_codec` _(optional)_: A module or a type implementing `Codec` (or `TryCodec`) with `encode` and
+///   `decode` functions (`try_encode` and `try_decode` for fallible derives), to be used on each direction
+/// - `via_adapter = MyAdapter` _(optional)_: A type implementing `Adapter` (or `TryAdapter` for fallible derives) for
+///   the field types, to be used as a reusable conversion policy
+/// - `via = Intermediate` _(optional)_: The field is converted into the `Intermediate` type first and then into the
+///   other one, using `Into` on both steps
+/// - `via(Intermediate, try)` _(optional)_: The same as above but using `TryInto` on both steps, only for the
+///   `try_from` or `try_into` derives
 ///
 /// **&#xb9;** When providing additional fields without defaults, the `From` and `TryFrom` traits can't be derived and
 /// a custom function will be required instead. When deriving `into` or `try_into`, the `ty` must be provided as well.
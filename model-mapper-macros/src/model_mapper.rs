@@ -1,22 +1,25 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
 
 use darling::{
-    ast::{Data, Fields},
+    ast::{Data, Fields, Style},
     util::{Override, SpannedValue},
     FromDeriveInput,
 };
-use heck::ToSnakeCase;
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use macro_field_utils::{FieldInfo, FieldsCollector, FieldsHelper, VariantsHelper};
 use proc_macro2::TokenStream;
-use proc_macro_error2::abort_if_dirty;
+use proc_macro_error2::{abort_if_dirty, emit_error};
 use quote::{format_ident, quote, ToTokens};
-use syn::{fold::Fold, parse_quote, visit::Visit};
+use syn::{fold::Fold, parse_quote, spanned::Spanned, visit::Visit};
 
 use crate::{input::*, type_path_ext::*};
 
 pub(crate) fn r#impl(input: syn::DeriveInput) -> TokenStream {
     // Parse input
-    let opts = match MapperOpts::from_derive_input(&input) {
+    let mut opts = match MapperOpts::from_derive_input(&input) {
         Ok(o) => o,
         Err(e) => {
             return e.write_errors();
@@ -42,16 +45,47 @@ pub(crate) fn r#impl(input: syn::DeriveInput) -> TokenStream {
             e.iter().for_each(|v| v.validate(&derive_items));
         }
     }
+    // Compute the names of the fields or variants on the other types, following the rename rules
+    for derive in &derive_items {
+        match &mut opts.data {
+            Data::Struct(s) => {
+                s.fields.iter_mut().for_each(|f| f.apply_rename_rules(derive));
+            }
+            Data::Enum(e) => {
+                e.iter_mut().for_each(|v| v.apply_rename_rules(derive));
+            }
+        }
+    }
+    abort_if_dirty();
+
+    // Nested fields must be read from fields of the other type not mapped by any other field
+    if let Data::Struct(struct_fields) = &opts.data {
+        derive_items
+            .iter()
+            .filter(|d| d.from.is_some() || d.try_from.is_some())
+            .for_each(|d| validate_from_paths(d, struct_fields));
+        abort_if_dirty();
+    }
+
+    let str_item = opts.str_item();
+    let int_item = opts.int_item();
+    validate_str(str_item, &opts.data);
+    validate_int(int_item, &opts.data);
     abort_if_dirty();
 
     let mut output = TokenStream::new();
 
     // Derive each requested type
     for derive in derive_items {
+        if let Some(via) = derive.via.as_deref() {
+            // Compose the conversions through the intermediate type
+            derive_via(ident, &opts.generics, &derive, via).to_tokens(&mut output);
+            continue;
+        }
         match &opts.data {
             Data::Struct(struct_fields) => {
                 // Derive the struct
-                derive_struct(ident, &opts.generics, derive, struct_fields).to_tokens(&mut output);
+                derive_struct(ident, &opts.vis, &opts.generics, derive, struct_fields).to_tokens(&mut output);
             }
             Data::Enum(enum_variants) => {
                 // Derive the enum
@@ -60,17 +94,75 @@ pub(crate) fn r#impl(input: syn::DeriveInput) -> TokenStream {
         }
     }
 
+    // Derive the string mapping
+    if let (Some(str_item), Data::Enum(enum_variants)) = (str_item, &opts.data) {
+        derive_str(ident, &opts.generics, str_item, enum_variants).to_tokens(&mut output);
+    }
+
+    // Derive the integer mapping
+    if let (Some(int_item), Data::Enum(enum_variants)) = (int_item, &opts.data) {
+        derive_int(ident, &opts.generics, int_item, enum_variants).to_tokens(&mut output);
+    }
+
+    // Derive the upgrade chains from previous versions
+    if let Some(upgrade) = opts.upgrade_from.as_ref() {
+        if !opts.generics.params.is_empty() {
+            emit_error!(upgrade.span(), "Upgrade chains are not supported for generic types");
+            abort_if_dirty();
+        }
+        derive_upgrades(ident, &opts.vis, upgrade).to_tokens(&mut output);
+    }
+
     output
 }
 
 fn derive_struct(
     ident: &syn::Ident,
+    vis: &syn::Visibility,
     generics: &syn::Generics,
     derive: ItemInput,
     struct_fields: &Fields<FieldReceiver>,
 ) -> TokenStream {
     let mut output = TokenStream::new();
 
+    // Derive from or into many types at once
+    if let DeriveTy::Tuple(_) = derive.path.as_ref() {
+        if let Some(from) = derive.from.as_ref() {
+            derive_struct_from_parts(from, ident, generics, &derive, struct_fields, false).to_tokens(&mut output);
+        }
+        if let Some(try_from) = derive.try_from.as_ref() {
+            derive_struct_from_parts(try_from, ident, generics, &derive, struct_fields, true).to_tokens(&mut output);
+        }
+        if let Some(into) = derive.into.as_ref() {
+            derive_struct_into_parts(into, ident, vis, generics, &derive, struct_fields, false).to_tokens(&mut output);
+        }
+        if let Some(try_into) = derive.try_into.as_ref() {
+            derive_struct_into_parts(try_into, ident, vis, generics, &derive, struct_fields, true)
+                .to_tokens(&mut output);
+        }
+        return output;
+    }
+
+    // Derive from or into plain tuples, by position
+    if let DeriveTy::TupleType(_) = derive.path.as_ref() {
+        if derive.from.is_some() {
+            derive_struct_from_tuple(ident, generics, &derive, struct_fields, false).to_tokens(&mut output);
+        }
+        if derive.try_from.is_some() {
+            derive_struct_from_tuple(ident, generics, &derive, struct_fields, true).to_tokens(&mut output);
+        }
+        if derive.into.is_some() {
+            derive_struct_into_tuple(ident, generics, &derive, struct_fields, false).to_tokens(&mut output);
+        }
+        if derive.try_into.is_some() {
+            derive_struct_into_tuple(ident, generics, &derive, struct_fields, true).to_tokens(&mut output);
+        }
+        return output;
+    }
+
+    // Unnamed fields mapped by name or index are accessed through their positions, as if they were named
+    let struct_fields = &struct_fields_by_member(&derive, struct_fields);
+
     // Derive `From`
     if let Some(from) = derive.from.as_ref() {
         derive_struct_from(from, ident, generics, &derive, struct_fields, false).to_tokens(&mut output);
@@ -78,7 +170,7 @@ fn derive_struct(
 
     // Derive reverse `From`
     if let Some(into) = derive.into.as_ref() {
-        derive_struct_into(into, ident, generics, &derive, struct_fields, false).to_tokens(&mut output);
+        derive_struct_into(into, ident, vis, generics, &derive, struct_fields, false).to_tokens(&mut output);
     }
 
     // Derive `TryFrom`
@@ -88,7 +180,7 @@ fn derive_struct(
 
     // Derive reverse `TryFrom`
     if let Some(try_into) = derive.try_into.as_ref() {
-        derive_struct_into(try_into, ident, generics, &derive, struct_fields, true).to_tokens(&mut output);
+        derive_struct_into(try_into, ident, vis, generics, &derive, struct_fields, true).to_tokens(&mut output);
     }
 
     output
@@ -102,6 +194,9 @@ fn derive_enum(
 ) -> TokenStream {
     let mut output = TokenStream::new();
 
+    // Unnamed fields of variants mapped by member are accessed through their positions, as if they were named
+    let enum_variants = &enum_variants_by_member(&derive, enum_variants);
+
     // Derive `From`
     if let Some(from) = derive.from.as_ref() {
         derive_enum_from(from, ident, generics, &derive, enum_variants, false).to_tokens(&mut output);
@@ -134,7 +229,8 @@ fn derive_struct_from(
     is_try: bool,
 ) -> TokenStream {
     // Derive from the other type into self
-    let original_from_ty = derive.path.as_ref();
+    let derive_ty = derive.path.as_ref();
+    let original_from_ty = derive_ty.single().expect("single type");
     let into_ty = ident;
     let (_, into_ty_generics, _) = generics.split_for_impl();
 
@@ -144,7 +240,7 @@ fn derive_struct_from(
 
     // Process other_ty to append where clauses
     struct_fields.iter().for_each(|f| {
-        if let Some(other_ty) = f.other_ty_for(original_from_ty) {
+        if let Some(other_ty) = f.other_ty_for(derive_ty) {
             let ty_ident = other_ty;
             let resolved_ident = generics_rename_map.get(ty_ident).unwrap_or(ty_ident);
             let field_ty = &f.ty;
@@ -160,96 +256,53 @@ fn derive_struct_from(
     // In patterns we must not use generics
     let from_ty = strip_generics(&from_ty_with_generics);
 
-    // The other type has
-    let from_ty_fields_helper = FieldsHelper::new(struct_fields)
-        // every non-skipped field of self
-        .filtering(|_ix, f| f.skip_for(original_from_ty).is_none())
-        // every additional field explicitly set
-        .extra_fields(derive.add.iter().map(|f| f.field.as_ref()))
-        // any other field ignored, if set
-        .ignore_all_extra(derive.ignore_extra.is_present());
-
-    // Self type has
-    let into_ty_fields_helper = FieldsHelper::new(struct_fields)
-        // every non-skipped field (as it's on the from)
-        .filtering(|_ix, f| f.skip_for(original_from_ty).is_none())
-        // skipped fields with the custom value provided
-        .extra_fields_with(
-            struct_fields
-                .iter()
-                .filter_map(|f| f.skip_for(original_from_ty).map(|skip| (f, skip)))
-                .filter_map(|(f, skip)| {
-                    f.ident.as_ref().map(|field| {
-                        (
-                            field,
-                            // populated with
-                            skip.as_ref()
-                                .explicit()
-                                .and_then(|s| s.default.as_deref())
-                                // if default enabled: the default expression provided or Default::default()
-                                .map(|d|
-                                    d.clone()
-                                        .explicit()
-                                        .map(|e| e.value)
-                                        .unwrap_or_else(|| parse_quote!(Default::default()))
-                                )
-                                // or just the field ident (renamed), as it will be provided on the function parameters
-                                .unwrap_or_else(|| {
-                                    let ident = if let Some(rename) = f.rename_for(original_from_ty)  {
-                                        rename
-                                    } else {
-                                        field
-                                    };
-                                    parse_quote!(#ident)
-                                }),
-                        )
-                    })
-                }),
-        );
+    // Deconstruct the `from` input to retrieve the inner fields, or read them through its getters
+    let getters = DeriveInput::uses_getters(from);
+    let deconstructed_from = if getters {
+        let getters = struct_from_getters(derive, struct_fields, is_try);
+        // bound to a reserved name first, so it's not shadowed by the fields of self
+        quote!(let __from = from; #( #getters )*)
+    } else if let Some(variant) = derive.variant.as_deref() {
+        // Any other variant of the other enum fails, or populates self with the default value
+        let pattern = struct_from_pattern(derive, struct_fields, None);
+        let otherwise = match derive.default.as_deref() {
+            Some(default) => {
+                let value = default
+                    .clone()
+                    .explicit()
+                    .map(|d| d.value)
+                    .unwrap_or_else(|| parse_quote!(Default::default()));
+                if is_try {
+                    quote!(return Ok(#value);)
+                } else {
+                    quote!(return #value;)
+                }
+            }
+            None => {
+                let msg = format!(
+                    "Expected variant '{variant}' of '{}'",
+                    original_from_ty.path.segments.last().expect("type path").ident
+                );
+                quote!(return Err(::anyhow::anyhow!(#msg));)
+            }
+        };
+        quote!(let #from_ty::#variant #pattern = from else { #otherwise };)
+    } else {
+        let pattern = struct_from_pattern(derive, struct_fields, None);
+        quote!(let #from_ty #pattern = from;)
+    };
 
-    // Deconstruct the `from` input to retrieve the inner fields
-    let deconstructed_from = from_ty_fields_helper
-        .left_collector(|ix, f| {
-            let ident = if let Some(rename) = f.rename_for(original_from_ty) {
-                rename.clone()
-            } else {
-                f.as_ident(ix)
-            };
-            quote!(#ident)
-        })
-        .right_collector(FieldsCollector::ident)
-        .collect();
+    // Unwrap the optional values of the nested fields
+    let prelude = struct_from_prelude(derive, struct_fields);
+    let deconstructed_from = quote!(#deconstructed_from #( #prelude )*);
 
     // Produce `into` body using the `with`
-    let into_body = into_ty_fields_helper
-        .right_collector(|ix, f| {
-            let ident = f.as_ident(ix);
-            f.build_into_for(true, is_try, &ident, original_from_ty)
-        })
-        .collect();
+    let into_body = struct_from_body(derive, struct_fields, is_try, getters);
 
     // If we're deriving a custom function
     if let Some(custom) = Override::as_ref(from).explicit().and_then(|e| e.custom.as_deref()) {
         // Collect the skipped fields that doesn't have a default value
-        let external_fields = struct_fields
-            .iter()
-            .filter(|f| {
-                f.skip_for(original_from_ty)
-                    .filter(|map| map.as_ref().explicit().map(|s| s.default.is_none()).unwrap_or(true))
-                    .is_some()
-            })
-            .filter_map(|f| {
-                let ty = &f.ty;
-                f.ident.as_ref().map(|i| {
-                    let ident = if let Some(rename) = f.rename_for(original_from_ty) {
-                        rename
-                    } else {
-                        i
-                    };
-                    quote!(#ident: #ty)
-                })
-            })
-            .collect::<Vec<_>>();
+        let external_fields = struct_from_external_fields(derive, struct_fields);
 
         // Compute the function name, whether is provided or not
         let fn_name = custom.clone().explicit().unwrap_or_else(|| {
@@ -271,13 +324,13 @@ fn derive_struct_from(
         if is_try {
             quote!(
                 #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
                 impl #impl_generics #into_ty #into_ty_generics #where_clause {
                     #[doc = #doc]
                     #[allow(clippy::too_many_arguments)]
                     pub fn #fn_name(from: #from_ty_with_generics, #( #external_fields ),*)
                         -> ::std::result::Result<Self, ::anyhow::Error> {
-                        let #from_ty #deconstructed_from = from;
+                        #deconstructed_from
                         Ok(Self #into_body)
                     }
                 }
@@ -285,12 +338,12 @@ fn derive_struct_from(
         } else {
             quote!(
                 #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
                 impl #impl_generics #into_ty #into_ty_generics #where_clause {
                     #[doc = #doc]
                     #[allow(clippy::too_many_arguments)]
                     pub fn #fn_name(from: #from_ty_with_generics, #( #external_fields ),*) -> Self {
-                        let #from_ty #deconstructed_from = from;
+                        #deconstructed_from
                         Self #into_body
                     }
                 }
@@ -300,13 +353,13 @@ fn derive_struct_from(
         // Implement the [TryFrom] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
             impl #impl_generics TryFrom<#from_ty_with_generics> for #into_ty #into_ty_generics #where_clause {
                 type Error = ::anyhow::Error;
 
                 fn try_from(from: #from_ty_with_generics)
                     -> ::std::result::Result<Self, <Self as TryFrom<#from_ty_with_generics>>::Error> {
-                    let #from_ty #deconstructed_from = from;
+                    #deconstructed_from
                     Ok(Self #into_body)
                 }
             }
@@ -315,10 +368,10 @@ fn derive_struct_from(
         // Implement the [From] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
             impl #impl_generics From<#from_ty_with_generics> for #into_ty #into_ty_generics #where_clause {
                 fn from(from: #from_ty_with_generics) -> Self {
-                    let #from_ty #deconstructed_from = from;
+                    #deconstructed_from
                     Self #into_body
                 }
             }
@@ -326,201 +379,1385 @@ fn derive_struct_from(
     }
 }
 
-fn derive_struct_into(
-    into: &SpannedValue<Override<DeriveInput>>,
+fn derive_struct_from_parts(
+    from: &SpannedValue<Override<DeriveInput>>,
     ident: &syn::Ident,
     generics: &syn::Generics,
     derive: &ItemInput,
     struct_fields: &Fields<FieldReceiver>,
     is_try: bool,
 ) -> TokenStream {
-    // Derive from self into the other type
-    let from_ty = ident;
-    let original_into_ty = derive.path.as_ref();
-    let (_, from_ty_generics, _) = generics.split_for_impl();
-
-    // Merge generics
-    let (mut all_generics, into_ty_with_generics, generics_rename_map) =
-        process_generics(generics, original_into_ty, false);
+    // Derive from every part of the other type into self
+    let derive_ty = derive.path.as_ref();
+    let parts = derive_ty.parts();
+    let into_ty = ident;
 
     // Process other_ty to append where clauses
+    let mut all_generics = generics.clone();
     struct_fields.iter().for_each(|f| {
-        if let Some(other_ty) = f.other_ty_for(original_into_ty) {
-            let ty_ident = other_ty;
-            let resolved_ident = generics_rename_map.get(ty_ident).unwrap_or(ty_ident);
+        if let Some(other_ty) = f.other_ty_for(derive_ty) {
             let field_ty = &f.ty;
             let where_clause = all_generics.make_where_clause();
-            where_clause
-                .predicates
-                .push(parse_quote!(#field_ty: Into<#resolved_ident>));
+            where_clause.predicates.push(parse_quote!(#other_ty: Into<#field_ty>));
         }
     });
+    let (impl_generics, into_ty_generics, where_clause) = all_generics.split_for_impl();
 
-    // Split generics for impl and where
-    let (impl_generics, _, where_clause) = all_generics.split_for_impl();
+    // Every part gets its own parameter, named after its type
+    let part_idents = part_idents(derive_ty);
     // In patterns we must not use generics
-    let into_ty = strip_generics(&into_ty_with_generics);
-
-    // Self type has every field (whether it's used or not)
-    let from_ty_fields_helper = FieldsHelper::new(struct_fields);
+    let part_tys = parts.iter().map(|p| strip_generics(p)).collect::<Vec<_>>();
 
-    // The other type has
-    let into_ty_fields_helper = FieldsHelper::new(struct_fields)
-        // every non-skipped field
-        .filtering(|_ix, f| f.skip_for(original_into_ty).is_none())
-        // every additional field explicitly set
-        .extra_fields_with(derive.add.iter().map(|i| {
-            let field = i.field.as_ref();
-            (
-                field,
-                // populated with
-                i.default
-                    .as_deref()
-                    // if default enabled: the default expression provided or Default::default()
-                    .map(|d| d
-                        .clone()
-                        .explicit()
-                        .map(|d| d.value)
-                        .unwrap_or_else(|| parse_quote!(Default::default()))
-                    )
-                    // or just the field ident, as it will be provided on the function parameters
-                    .unwrap_or_else(|| parse_quote!(#field)),
-            )
-        }))
-        // any other ignored field, with the default value
-        .include_all_default(derive.ignore_extra.is_present());
+    // Deconstruct every part to retrieve the inner fields
+    let deconstructed_parts = (0..parts.len())
+        .map(|ix| struct_from_pattern(derive, struct_fields, Some(ix)))
+        .collect::<Vec<_>>();
 
-    // Deconstruct the `from` input to retrieve the inner fields
-    let deconstructed_from = from_ty_fields_helper
-        .right_collector(|ix, f| {
-            let ident = if let Some(rename) = f.rename_for(original_into_ty) {
-                rename.clone()
-            } else {
-                f.as_ident(ix)
-            };
-            quote!(#ident)
-        })
-        .collect();
+    // Unwrap the optional values of the nested fields
+    let prelude = struct_from_prelude(derive, struct_fields);
 
     // Produce `into` body using the `with`
-    let into_body = into_ty_fields_helper
-        .left_collector(|ix, f| {
-            let ident = if let Some(rename) = f.rename_for(original_into_ty) {
-                rename.clone()
-            } else {
-                f.as_ident(ix)
-            };
-            quote!(#ident)
-        })
-        .right_collector(|ix, f| {
-            let ident = if let Some(rename) = f.rename_for(original_into_ty) {
-                rename.clone()
-            } else {
-                f.as_ident(ix)
-            };
-            f.build_into_for(false, is_try, &ident, original_into_ty)
-        })
-        .collect();
-
-    // If we're deriving a custom function
-    if let Some(custom) = Override::as_ref(into).explicit().and_then(|e| e.custom.as_deref()) {
-        // Collect the additional fields that doesn't have a default value
-        let external_fields = derive
-            .add
+    let into_body = struct_from_body(derive, struct_fields, is_try, false);
+
+    // Collect the skipped fields that doesn't have a default value
+    let external_fields = struct_from_external_fields(derive, struct_fields);
+
+    // Compute the function name, whether is provided or not
+    let custom = Override::as_ref(from).explicit().and_then(|e| e.custom.as_deref());
+    let fn_name = custom
+        .and_then(|c| c.clone().explicit())
+        .unwrap_or_else(|| format_ident!("{}", if is_try { "try_from_parts" } else { "from_parts" }));
+
+    // Compute the method doc
+    let doc = format!(
+        "{} a new [{into_ty}] from its parts: {}",
+        if is_try { "Tries to build" } else { "Builds" },
+        parts
             .iter()
-            .filter(|a| a.default.is_none())
-            .map(|f| {
-                let ident = f.field.as_ref();
-                let ty = f.ty.as_ref().expect("'ty' must be provided").as_ref();
-                quote!(#ident: #ty)
-            })
-            .collect::<Vec<_>>();
-
-        // Compute the function name, whether is provided or not
-        let fn_name = custom.clone().explicit().unwrap_or_else(|| {
-            format_ident!(
-                "{}_{}",
-                if is_try { "try_into" } else { "into" },
-                original_into_ty.to_token_stream().to_string().to_snake_case()
-            )
-        });
+            .map(|p| format!("[{}]", p.to_token_stream().to_string().replace(' ', "")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
 
-        // Compute the method doc
-        let doc = format!(
-            "{} a new [{}] from a [{from_ty}]",
-            if is_try { "Tries to build" } else { "Builds" },
-            original_into_ty.to_token_stream().to_string().replace(' ', "")
-        );
-
-        // Implement the custom function
-        if is_try {
-            quote!(
-                #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
-                impl #impl_generics #from_ty #from_ty_generics #where_clause {
-                    #[doc = #doc]
-                    #[allow(clippy::too_many_arguments)]
-                    pub fn #fn_name(self, #( #external_fields ),*)
-                        -> ::std::result::Result<#into_ty_with_generics, ::anyhow::Error> {
-                        let #from_ty #deconstructed_from = self;
-                        Ok(#into_ty #into_body)
-                    }
+    // Implement the function
+    let mut output = if is_try {
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics #into_ty #into_ty_generics #where_clause {
+                #[doc = #doc]
+                #[allow(clippy::too_many_arguments)]
+                pub fn #fn_name(#( #part_idents: #parts, )* #( #external_fields ),*)
+                    -> ::std::result::Result<Self, ::anyhow::Error> {
+                    let ( #( #part_tys #deconstructed_parts ),* ) = ( #( #part_idents ),* );
+                    #( #prelude )*
+                    Ok(Self #into_body)
                 }
-            )
-        } else {
-            quote!(
-                #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
-                impl #impl_generics #from_ty #from_ty_generics #where_clause {
-                    #[doc = #doc]
-                    #[allow(clippy::too_many_arguments)]
-                    pub fn #fn_name(self, #( #external_fields ),*) -> #into_ty_with_generics {
-                        let #from_ty #deconstructed_from = self;
-                        #into_ty #into_body
-                    }
+            }
+        )
+    } else {
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics #into_ty #into_ty_generics #where_clause {
+                #[doc = #doc]
+                #[allow(clippy::too_many_arguments)]
+                pub fn #fn_name(#( #part_idents: #parts, )* #( #external_fields ),*) -> Self {
+                    let ( #( #part_tys #deconstructed_parts ),* ) = ( #( #part_idents ),* );
+                    #( #prelude )*
+                    Self #into_body
                 }
-            )
-        }
-    } else if is_try {
+            }
+        )
+    };
+
+    // Custom derives just provide the function
+    if custom.is_some() {
+        return output;
+    }
+
+    if is_try {
         // Implement the [TryFrom] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
-            impl #impl_generics TryFrom<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
+            impl #impl_generics TryFrom<#derive_ty> for #into_ty #into_ty_generics #where_clause {
                 type Error = ::anyhow::Error;
 
-                fn try_from(from: #from_ty #from_ty_generics)
-                    -> ::std::result::Result<Self, <Self as TryFrom<#from_ty #from_ty_generics>>::Error> {
-                    let #from_ty #deconstructed_from = from;
-                    Ok(Self #into_body)
+                fn try_from(( #( #part_idents ),* ): #derive_ty)
+                    -> ::std::result::Result<Self, <Self as TryFrom<#derive_ty>>::Error> {
+                    Self::#fn_name( #( #part_idents ),* )
                 }
             }
         )
+        .to_tokens(&mut output);
     } else {
         // Implement the [From] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
-            impl #impl_generics From<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
-                fn from(from: #from_ty #from_ty_generics) -> Self {
-                    let #from_ty #deconstructed_from = from;
-                    Self #into_body
+            impl #impl_generics From<#derive_ty> for #into_ty #into_ty_generics #where_clause {
+                fn from(( #( #part_idents ),* ): #derive_ty) -> Self {
+                    Self::#fn_name( #( #part_idents ),* )
                 }
             }
         )
+        .to_tokens(&mut output);
     }
+
+    output
 }
 
-fn derive_enum_from(
-    from: &SpannedValue<Override<DeriveInput>>,
-    ident: &syn::Ident,
-    generics: &syn::Generics,
+/// Switches unnamed fields into the named style when any of them is mapped by name or index, so they're accessed
+/// through their positions (`Self { 0: .. }`) and can be mapped into fields of a different shape
+fn struct_fields_by_member<'f>(
     derive: &ItemInput,
-    enum_variants: &[VariantReceiver],
-    is_try: bool,
-) -> TokenStream {
+    struct_fields: &'f Fields<FieldReceiver>,
+) -> Cow<'f, Fields<FieldReceiver>> {
+    let derive_ty = derive.path.as_ref();
+    if struct_fields.is_tuple()
+        && struct_fields
+            .iter()
+            .any(|f| f.index_for(derive_ty).is_some() || f.name_for(derive_ty).is_some())
+    {
+        Cow::Owned(Fields::new(Style::Struct, struct_fields.fields.clone()))
+    } else {
+        Cow::Borrowed(struct_fields)
+    }
+}
+
+/// Assigns every field of self mapped directly to an element of the plain tuple type, either by its index or by the
+/// order they're declared. Returns the position of the field (if any) mapped to each element.
+fn struct_tuple_elements(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>, from: bool) -> Vec<Option<usize>> {
+    let derive_ty = derive.path.as_ref();
+    let tuple = derive_ty.tuple_type().expect("tuple type");
+
+    let mut elements = vec![None; tuple.elems.len()];
+    for (next, (ix, f)) in struct_fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.maps_directly_for(from, derive_ty))
+        .enumerate()
+    {
+        let element = f.index_for(derive_ty).unwrap_or(next);
+        let span = f.ident.as_ref().map(|i| i.span()).unwrap_or_else(|| f.ty.span());
+        match elements.get_mut(element) {
+            None => emit_error!(span, "The tuple has no element {}", element),
+            Some(Some(_)) => emit_error!(span, "Element {} of the tuple is already mapped", element),
+            Some(element) => *element = Some(ix),
+        }
+    }
+
+    // Elements not mapped by any field must be ignored
+    if !derive.ignore_extra.is_present() && !derive.fill_default.is_present() {
+        for (element, _) in elements.iter().enumerate().filter(|(_, ix)| ix.is_none()) {
+            emit_error!(
+                derive.path.span(),
+                "Element {} of the tuple isn't mapped, map it with 'index' or enable 'ignore_extra'",
+                element
+            );
+        }
+    }
+
+    elements
+}
+
+fn derive_struct_from_tuple(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+) -> TokenStream {
+    // Derive from the plain tuple into self
+    let from_ty = derive.path.as_ref();
+    let into_ty = ident;
+    let (impl_generics, into_ty_generics, where_clause) = generics.split_for_impl();
+
+    // Deconstruct the tuple binding each element to the field mapped from it
+    let elements = struct_tuple_elements(derive, struct_fields, true)
+        .into_iter()
+        .map(|ix| match ix {
+            Some(ix) => struct_fields.fields[ix].as_ident(ix).to_token_stream(),
+            None => quote!(_),
+        })
+        .collect::<Vec<_>>();
+
+    // Produce `into` body using the `with`
+    let into_body = struct_from_body(derive, struct_fields, is_try, false);
+
+    if is_try {
+        // Implement the [TryFrom] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics TryFrom<#from_ty> for #into_ty #into_ty_generics #where_clause {
+                type Error = ::anyhow::Error;
+
+                fn try_from(( #( #elements ),* ,): #from_ty)
+                    -> ::std::result::Result<Self, <Self as TryFrom<#from_ty>>::Error> {
+                    Ok(Self #into_body)
+                }
+            }
+        )
+    } else {
+        // Implement the [From] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics From<#from_ty> for #into_ty #into_ty_generics #where_clause {
+                fn from(( #( #elements ),* ,): #from_ty) -> Self {
+                    Self #into_body
+                }
+            }
+        )
+    }
+}
+
+/// Builds the pattern to deconstruct the other type (or one of its parts) when deriving from it
+fn struct_from_pattern(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>, part: Option<usize>) -> TokenStream {
+    let derive_ty = derive.path.as_ref();
+    // Whether the field belongs to the deconstructed part
+    let in_part = move |f: &FieldReceiver| part.is_none_or(|part| f.part_for(derive_ty) == part);
+    let add_in_part = move |a: &&AddInput| part.is_none_or(|part| a.part_for(true, derive_ty) == part);
+
+    // Fields of the other type containing nested fields of self, once
+    let mut from_path_roots = Vec::new();
+    for root in struct_fields
+        .iter()
+        .filter(|f| in_part(f))
+        .filter_map(|f| f.source_root_for(derive_ty))
+    {
+        if !from_path_roots.contains(&root) {
+            from_path_roots.push(root);
+        }
+    }
+
+    // The other type has
+    FieldsHelper::new(struct_fields)
+        // every field of self mapped directly
+        .filtering(move |_ix, f| in_part(f) && f.maps_directly_for(true, derive_ty))
+        // every additional field explicitly set
+        .extra_fields(derive.add.iter().filter(add_in_part).map(|f| f.field.as_ref()))
+        // every field containing the nested fields of self
+        .extra_fields(from_path_roots.iter().copied())
+        // every field flattened from self
+        .extra_fields_with(struct_fields.iter().filter(|f| in_part(f)).flat_map(|f| f.flattened_fields_for(derive_ty)))
+        // every field combined into self
+        .extra_fields_with(struct_fields.iter().filter(|f| in_part(f)).flat_map(|f| f.combined_fields_for(derive_ty)))
+        // every field explicitly ignored
+        .ignore_extra(match part {
+            Some(part) => derive.ignored_in_part(part),
+            None => derive.ignored(),
+        })
+        // any other field ignored, if set
+        .ignore_all_extra(derive.ignore_extra.is_present() || derive.fill_default.is_present())
+        .left_collector(|ix, f| f.other_member_for(derive_ty, ix))
+        .right_collector(FieldsCollector::ident)
+        .collect()
+}
+
+/// Validates the fields of the other type containing nested fields of self aren't mapped by any other field, emitting
+/// errors otherwise
+fn validate_from_paths(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>) {
+    let derive_ty = derive.path.as_ref();
+    for part in 0..derive_ty.parts().len() {
+        let in_part = |f: &&FieldReceiver| f.part_for(derive_ty) == part;
+        // Every field of the other type deconstructed for any other reason
+        let mut mapped = struct_fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| in_part(f) && f.maps_directly_for(true, derive_ty))
+            .map(|(ix, f)| f.other_member_for(derive_ty, ix).to_string())
+            .collect::<Vec<_>>();
+        mapped.extend(
+            derive
+                .add
+                .iter()
+                .filter(|a| a.part_for(true, derive_ty) == part)
+                .map(|a| a.field.to_string()),
+        );
+        mapped.extend(derive.ignored_in_part(part).into_iter().map(|i| i.to_string()));
+        for f in struct_fields.iter().filter(in_part) {
+            mapped.extend(
+                f.flattened_fields_for(derive_ty)
+                    .into_iter()
+                    .chain(f.combined_fields_for(derive_ty))
+                    .map(|(field, _)| field.to_string()),
+            );
+        }
+        for root in struct_fields
+            .iter()
+            .filter(in_part)
+            .filter_map(|f| f.source_root_for(derive_ty))
+        {
+            if mapped.contains(&root.to_string()) {
+                emit_error!(
+                    root.span(),
+                    "'{}' is already mapped, it can't be read by 'from_path' as well",
+                    root
+                );
+            }
+        }
+    }
+}
+
+/// Builds the statements unwrapping the optional values along the nested fields of the other type when deriving from
+/// it, once for every field sharing them
+fn struct_from_prelude(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>) -> Vec<TokenStream> {
+    let derive_ty = derive.path.as_ref();
+    let mut unwrapped = Vec::new();
+    let mut prelude = Vec::new();
+    for (described, unwrap) in struct_fields
+        .iter()
+        .filter(|f| f.skip_for(derive_ty).is_none())
+        .flat_map(|f| f.build_from_path_unwraps(derive_ty))
+    {
+        if !unwrapped.contains(&described) {
+            unwrapped.push(described);
+            prelude.push(unwrap);
+        }
+    }
+    prelude
+}
+
+/// Builds the statements reading the fields of the other type through its getter methods when deriving from it
+fn struct_from_getters(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>, is_try: bool) -> Vec<TokenStream> {
+    let derive_ty = derive.path.as_ref();
+
+    // The other type has
+    let mut getters = struct_fields
+        .iter()
+        .enumerate()
+        // every field of self mapped directly, read through the same name (renamed) or the custom getter
+        .filter(|(_, f)| f.maps_directly_for(true, derive_ty))
+        .map(|(ix, f)| {
+            let binding = f.as_ident(ix);
+            let getter = f.getter_for(derive_ty);
+            let method = match getter {
+                Some(getter) => getter.to_token_stream(),
+                None => f.rename_for(derive_ty).cloned().unwrap_or_else(|| binding.clone()).to_token_stream(),
+            };
+            // converted right away, so the borrowed values are released before any getter taking it by value
+            let converted = f.build_into_for(true, is_try, &binding, derive);
+            (
+                getter.is_some_and(|g| g.is_by_value()),
+                quote!(let #binding = __from.#method(); let #binding = #converted;),
+            )
+        })
+        // every field flattened from self or combined into self, read through the same name
+        .chain(
+            struct_fields
+                .iter()
+                .flat_map(|f| [f.flattened_fields_for(derive_ty), f.combined_fields_for(derive_ty)])
+                .flatten()
+                .map(|(field, binding)| (false, quote!(let #binding = __from.#field();))),
+        )
+        .collect::<Vec<_>>();
+
+    // Getters taking the other type by value must be called last, so there can be just one of them
+    for getter in struct_fields
+        .iter()
+        .filter(|f| f.maps_directly_for(true, derive_ty))
+        .filter_map(|f| f.getter_for(derive_ty))
+        .filter(|g| g.is_by_value())
+        .skip(1)
+    {
+        emit_error!(getter.span(), "Only one getter can take the other type by value");
+    }
+    getters.sort_by_key(|(by_value, _)| *by_value);
+
+    getters.into_iter().map(|(_, getter)| getter).collect()
+}
+
+/// Builds the body of self when deriving from the other type
+fn struct_from_body(
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+    getters: bool,
+) -> TokenStream {
+    let derive_ty = derive.path.as_ref();
+    // Whether the skipped field is populated from the default value of self
+    let is_filled = |skip: &Override<SkipInput>| {
+        derive.fill_default.is_present() && skip.as_ref().explicit().map(|s| s.default.is_none()).unwrap_or(true)
+    };
+
+    // Self type has
+    FieldsHelper::new(struct_fields)
+        // every non-skipped field (as it's on the from)
+        .filtering(|_ix, f| f.skip_for(derive_ty).is_none())
+        // skipped fields with the custom value provided
+        .extra_fields_with(
+            struct_fields
+                .iter()
+                .filter_map(|f| f.skip_for(derive_ty).map(|skip| (f, skip)))
+                .filter(|(_, skip)| !is_filled(skip))
+                .filter_map(|(f, skip)| {
+                    f.ident.as_ref().map(|field| {
+                        (
+                            field,
+                            // populated with
+                            skip.as_ref()
+                                .explicit()
+                                .and_then(|s| s.default.as_deref())
+                                // if default enabled: the default expression provided or Default::default()
+                                .map(|d|
+                                    d.clone()
+                                        .explicit()
+                                        .map(|e| e.value)
+                                        .unwrap_or_else(|| parse_quote!(Default::default()))
+                                )
+                                // or just the field ident (renamed), as it will be provided on the function parameters
+                                .unwrap_or_else(|| {
+                                    let ident = if let Some(rename) = f.rename_for(derive_ty)  {
+                                        rename
+                                    } else {
+                                        field
+                                    };
+                                    parse_quote!(#ident)
+                                }),
+                        )
+                    })
+                }),
+        )
+        // skipped fields without a default value populated from the default value of self, if set
+        .include_all_default(struct_fields.iter().filter_map(|f| f.skip_for(derive_ty)).any(is_filled))
+        .left_collector(|ix, f| f.self_member(ix))
+        .right_collector(|ix, f| {
+            if f.is_flattened_for(derive_ty) {
+                return f.build_flatten_from(is_try, derive_ty);
+            }
+            let ident = f.as_ident(ix);
+            if f.source_root_for(derive_ty).is_some() {
+                return f.build_from_path(is_try, &ident, derive);
+            }
+            if !f.combined_fields_for(derive_ty).is_empty() {
+                return f.build_combine(is_try, derive_ty);
+            }
+            if getters {
+                // already converted when read
+                return quote!(#ident);
+            }
+            f.build_into_for(true, is_try, &ident, derive)
+        })
+        .collect()
+}
+
+/// Collects the skipped fields without a default value, to be provided on custom functions when deriving from the other
+/// type
+fn struct_from_external_fields(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>) -> Vec<TokenStream> {
+    let derive_ty = derive.path.as_ref();
+    struct_fields
+        .iter()
+        .filter(|f| {
+            !derive.fill_default.is_present()
+                && f.skip_for(derive_ty)
+                    .filter(|map| map.as_ref().explicit().map(|s| s.default.is_none()).unwrap_or(true))
+                    .is_some()
+        })
+        .filter_map(|f| {
+            let ty = &f.ty;
+            f.ident.as_ref().map(|i| {
+                let ident = if let Some(rename) = f.rename_for(derive_ty) {
+                    rename
+                } else {
+                    i
+                };
+                quote!(#ident: #ty)
+            })
+        })
+        .collect::<Vec<_>>()
+}
+
+fn derive_struct_into(
+    into: &SpannedValue<Override<DeriveInput>>,
+    ident: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+) -> TokenStream {
+    // Derive from self into the other type
+    let from_ty = ident;
+    let derive_ty = derive.path.as_ref();
+    let original_into_ty = derive_ty.single().expect("single type");
+    let (_, from_ty_generics, _) = generics.split_for_impl();
+
+    // Merge generics
+    let (mut all_generics, into_ty_with_generics, generics_rename_map) =
+        process_generics(generics, original_into_ty, false);
+
+    // Process other_ty to append where clauses
+    struct_fields.iter().for_each(|f| {
+        if let Some(other_ty) = f.other_ty_for(derive_ty) {
+            let ty_ident = other_ty;
+            let resolved_ident = generics_rename_map.get(ty_ident).unwrap_or(ty_ident);
+            let field_ty = &f.ty;
+            let where_clause = all_generics.make_where_clause();
+            where_clause
+                .predicates
+                .push(parse_quote!(#field_ty: Into<#resolved_ident>));
+        }
+    });
+
+    // Split generics for impl and where
+    let (impl_generics, _, where_clause) = all_generics.split_for_impl();
+    // In patterns we must not use generics
+    let into_ty = strip_generics(&into_ty_with_generics);
+
+    // Deconstruct the `from` input to retrieve the inner fields
+    let deconstructed_from = struct_into_pattern(derive, struct_fields);
+
+    // Produce `into` value using the `with`, either calling the constructor, through the builder or with a struct
+    // literal
+    let into_value = if let Some(constructor) = DeriveInput::constructor(into) {
+        struct_into_constructor(derive, struct_fields, is_try, constructor)
+    } else if let Some((builder, build)) = DeriveInput::builder(into) {
+        struct_into_builder(derive, struct_fields, is_try, builder, &build)
+    } else if let Some(variant) = derive.variant.as_deref() {
+        let into_body = struct_into_body(derive, struct_fields, is_try, None);
+        quote!(#into_ty::#variant #into_body)
+    } else {
+        let into_body = struct_into_body(derive, struct_fields, is_try, None);
+        quote!(#into_ty #into_body)
+    };
+
+    // Bind the fields of the flattened and split ones
+    let prelude = struct_into_prelude(derive, struct_fields, is_try);
+
+    // If we're deriving a custom function
+    if let Some(custom) = Override::as_ref(into).explicit().and_then(|e| e.custom.as_deref()) {
+        // Collect the additional fields that doesn't have a default value
+        let external_fields = struct_into_external_fields(derive);
+
+        // Compute the function name, whether is provided or not
+        let fn_name = custom.clone().explicit().unwrap_or_else(|| {
+            format_ident!(
+                "{}_{}",
+                if is_try { "try_into" } else { "into" },
+                original_into_ty.to_token_stream().to_string().to_snake_case()
+            )
+        });
+
+        // Compute the method doc
+        let doc = format!(
+            "{} a new [{}] from a [{from_ty}]",
+            if is_try { "Tries to build" } else { "Builds" },
+            original_into_ty.to_token_stream().to_string().replace(' ', "")
+        );
+
+        // Implement the custom function, providing its parameters through a staged builder if requested
+        if DeriveInput::params_builder(into) {
+            let output = if is_try {
+                quote!(::std::result::Result<#into_ty_with_generics, ::anyhow::Error>)
+            } else {
+                quote!(#into_ty_with_generics)
+            };
+            let into_value = if is_try { quote!(Ok(#into_value)) } else { into_value };
+            let body = quote!(
+                let #from_ty #deconstructed_from = __from;
+                #( #prelude )*
+                #into_value
+            );
+            derive_params_builder(
+                vis,
+                from_ty,
+                &from_ty_generics,
+                &all_generics,
+                derive,
+                (&fn_name, &doc, output),
+                body,
+            )
+        } else if is_try {
+            quote!(
+                #[automatically_derived]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
+                impl #impl_generics #from_ty #from_ty_generics #where_clause {
+                    #[doc = #doc]
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn #fn_name(self, #( #external_fields ),*)
+                        -> ::std::result::Result<#into_ty_with_generics, ::anyhow::Error> {
+                        let #from_ty #deconstructed_from = self;
+                        #( #prelude )*
+                        Ok(#into_value)
+                    }
+                }
+            )
+        } else {
+            quote!(
+                #[automatically_derived]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
+                impl #impl_generics #from_ty #from_ty_generics #where_clause {
+                    #[doc = #doc]
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn #fn_name(self, #( #external_fields ),*) -> #into_ty_with_generics {
+                        let #from_ty #deconstructed_from = self;
+                        #( #prelude )*
+                        #into_value
+                    }
+                }
+            )
+        }
+    } else if is_try {
+        // Implement the [TryFrom] trait
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics TryFrom<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
+                type Error = ::anyhow::Error;
+
+                fn try_from(from: #from_ty #from_ty_generics)
+                    -> ::std::result::Result<Self, <Self as TryFrom<#from_ty #from_ty_generics>>::Error> {
+                    let #from_ty #deconstructed_from = from;
+                    #( #prelude )*
+                    Ok(#into_value)
+                }
+            }
+        )
+    } else {
+        // Implement the [From] trait
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics From<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
+                fn from(from: #from_ty #from_ty_generics) -> Self {
+                    let #from_ty #deconstructed_from = from;
+                    #( #prelude )*
+                    #into_value
+                }
+            }
+        )
+    }
+}
+
+fn derive_struct_into_parts(
+    into: &SpannedValue<Override<DeriveInput>>,
+    ident: &syn::Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+) -> TokenStream {
+    // Derive from self into every part of the other type
+    let derive_ty = derive.path.as_ref();
+    let parts = derive_ty.parts();
+    let from_ty = ident;
+
+    // Process other_ty to append where clauses
+    let mut all_generics = generics.clone();
+    struct_fields.iter().for_each(|f| {
+        if let Some(other_ty) = f.other_ty_for(derive_ty) {
+            let field_ty = &f.ty;
+            let where_clause = all_generics.make_where_clause();
+            where_clause.predicates.push(parse_quote!(#field_ty: Into<#other_ty>));
+        }
+    });
+    let (impl_generics, from_ty_generics, where_clause) = all_generics.split_for_impl();
+
+    // In patterns we must not use generics
+    let part_tys = parts.iter().map(|p| strip_generics(p)).collect::<Vec<_>>();
+
+    // Deconstruct the `from` input to retrieve the inner fields
+    let deconstructed_from = struct_into_pattern(derive, struct_fields);
+
+    // Produce the body of every part using the `with`
+    let into_bodies = (0..parts.len())
+        .map(|ix| struct_into_body(derive, struct_fields, is_try, Some(ix)))
+        .collect::<Vec<_>>();
+
+    // Clone the fields mapped into many parts, for all but the last one
+    let part_idents = part_idents(derive_ty);
+    let clones = struct_fields
+        .iter()
+        .enumerate()
+        .flat_map(|(ix, f)| {
+            let ident = if let Some(rename) = f.rename_for(derive_ty) {
+                rename.clone()
+            } else {
+                f.as_ident(ix)
+            };
+            let targets = f.targets_for(derive_ty);
+            let cloned = targets[..targets.len() - 1]
+                .iter()
+                .map(|part| field_binding(&ident, &part_idents[*part]))
+                .collect::<Vec<_>>();
+            quote!(#( let #cloned = ::core::clone::Clone::clone(&#ident); )*)
+        })
+        .collect::<Vec<_>>();
+
+    // Bind the fields of the flattened and split ones
+    let prelude = struct_into_prelude(derive, struct_fields, is_try);
+
+    // Collect the additional fields that doesn't have a default value
+    let external_fields = struct_into_external_fields(derive);
+
+    // Compute the function name, whether is provided or not
+    let custom = Override::as_ref(into).explicit().and_then(|e| e.custom.as_deref());
+    let fn_name = custom
+        .and_then(|c| c.clone().explicit())
+        .unwrap_or_else(|| format_ident!("{}", if is_try { "try_into_parts" } else { "into_parts" }));
+
+    // Compute the method doc
+    let doc = format!(
+        "{} the parts of a [{from_ty}]: {}",
+        if is_try { "Tries to split" } else { "Splits" },
+        parts
+            .iter()
+            .map(|p| format!("[{}]", p.to_token_stream().to_string().replace(' ', "")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    // Provide the parameters of the custom function through a staged builder if requested
+    if DeriveInput::params_builder(into) {
+        let (output, parts) = if is_try {
+            (
+                quote!(::std::result::Result<#derive_ty, ::anyhow::Error>),
+                quote!(Ok(( #( #part_tys #into_bodies ),* ))),
+            )
+        } else {
+            (quote!(#derive_ty), quote!(( #( #part_tys #into_bodies ),* )))
+        };
+        let body = quote!(
+            let #from_ty #deconstructed_from = __from;
+            #( #clones )*
+            #( #prelude )*
+            #parts
+        );
+        return derive_params_builder(
+            vis,
+            from_ty,
+            &from_ty_generics,
+            &all_generics,
+            derive,
+            (&fn_name, &doc, output),
+            body,
+        );
+    }
+
+    // Implement the function
+    let mut output = if is_try {
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics #from_ty #from_ty_generics #where_clause {
+                #[doc = #doc]
+                #[allow(clippy::too_many_arguments)]
+                pub fn #fn_name(self, #( #external_fields ),*)
+                    -> ::std::result::Result<#derive_ty, ::anyhow::Error> {
+                    let #from_ty #deconstructed_from = self;
+                    #( #clones )*
+                    #( #prelude )*
+                    Ok(( #( #part_tys #into_bodies ),* ))
+                }
+            }
+        )
+    } else {
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics #from_ty #from_ty_generics #where_clause {
+                #[doc = #doc]
+                #[allow(clippy::too_many_arguments)]
+                pub fn #fn_name(self, #( #external_fields ),*) -> #derive_ty {
+                    let #from_ty #deconstructed_from = self;
+                    #( #clones )*
+                    #( #prelude )*
+                    ( #( #part_tys #into_bodies ),* )
+                }
+            }
+        )
+    };
+
+    // Custom derives just provide the function
+    if custom.is_some() {
+        return output;
+    }
+
+    if is_try {
+        // Implement the [TryFrom] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics TryFrom<#from_ty #from_ty_generics> for #derive_ty #where_clause {
+                type Error = ::anyhow::Error;
+
+                fn try_from(from: #from_ty #from_ty_generics)
+                    -> ::std::result::Result<Self, <Self as TryFrom<#from_ty #from_ty_generics>>::Error> {
+                    from.#fn_name()
+                }
+            }
+        )
+        .to_tokens(&mut output);
+    } else {
+        // Implement the [From] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics From<#from_ty #from_ty_generics> for #derive_ty #where_clause {
+                fn from(from: #from_ty #from_ty_generics) -> Self {
+                    from.#fn_name()
+                }
+            }
+        )
+        .to_tokens(&mut output);
+    }
+
+    output
+}
+
+fn derive_struct_into_tuple(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+) -> TokenStream {
+    // Derive from self into the plain tuple
+    let from_ty = ident;
+    let into_ty = derive.path.as_ref();
+    let (impl_generics, from_ty_generics, where_clause) = generics.split_for_impl();
+
+    // Deconstruct the `from` input to retrieve the inner fields
+    let deconstructed_from = struct_into_pattern(derive, struct_fields);
+
+    // Build each element of the tuple from the field mapped into it, or its default value
+    let derive_ty = derive.path.as_ref();
+    let elements = struct_tuple_elements(derive, struct_fields, false)
+        .into_iter()
+        .map(|ix| match ix {
+            Some(ix) => {
+                let f = &struct_fields.fields[ix];
+                let ident = if let Some(rename) = f.rename_for(derive_ty) {
+                    rename.clone()
+                } else {
+                    f.as_ident(ix)
+                };
+                f.build_into_for(false, is_try, &ident, derive)
+            }
+            None => quote!(Default::default()),
+        })
+        .collect::<Vec<_>>();
+
+    if is_try {
+        // Implement the [TryFrom] trait
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics TryFrom<#from_ty #from_ty_generics> for #into_ty #where_clause {
+                type Error = ::anyhow::Error;
+
+                fn try_from(from: #from_ty #from_ty_generics)
+                    -> ::std::result::Result<Self, <Self as TryFrom<#from_ty #from_ty_generics>>::Error> {
+                    let #from_ty #deconstructed_from = from;
+                    Ok(( #( #elements ),* ,))
+                }
+            }
+        )
+    } else {
+        // Implement the [From] trait
+        quote!(
+            #[automatically_derived]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
+            impl #impl_generics From<#from_ty #from_ty_generics> for #into_ty #where_clause {
+                fn from(from: #from_ty #from_ty_generics) -> Self {
+                    let #from_ty #deconstructed_from = from;
+                    ( #( #elements ),* ,)
+                }
+            }
+        )
+    }
+}
+
+/// Builds the pattern to deconstruct self when deriving into the other type
+fn struct_into_pattern(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>) -> TokenStream {
+    let derive_ty = derive.path.as_ref();
+
+    // Self type has every field (whether it's used or not)
+    FieldsHelper::new(struct_fields)
+        .left_collector(|ix, f| f.self_member(ix))
+        .right_collector(|ix, f| {
+            let ident = if let Some(rename) = f.rename_for(derive_ty) {
+                rename.clone()
+            } else {
+                f.as_ident(ix)
+            };
+            quote!(#ident)
+        })
+        .collect()
+}
+
+/// Builds the body of the other type (or one of its parts) when deriving into it
+fn struct_into_body(
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+    part: Option<usize>,
+) -> TokenStream {
+    let derive_ty = derive.path.as_ref();
+    // Whether the field is mapped into the built part
+    let in_part = move |f: &FieldReceiver| part.is_none_or(|part| f.targets_for(derive_ty).contains(&part));
+    let add_in_part = move |a: &&AddInput| part.is_none_or(|part| a.part_for(false, derive_ty) == part);
+
+    // The other type (or the part of it) has
+    let into_ty_fields_helper = FieldsHelper::new(struct_fields)
+        // every field mapped directly
+        .filtering(move |_ix, f| in_part(f) && f.maps_directly_for(false, derive_ty))
+        // every field flattened from self
+        .extra_fields_with(struct_fields.iter().filter(|f| in_part(f)).flat_map(|f| f.flattened_fields_for(derive_ty)))
+        // every field split from self
+        .extra_fields_with(struct_fields.iter().filter(|f| in_part(f)).flat_map(|f| f.split_fields_for(derive_ty)))
+        // every additional field explicitly set
+        .extra_fields_with(derive.add.iter().filter(add_in_part).map(|i| {
+            let field = i.field.as_ref();
+            (
+                field,
+                // populated with
+                i.default
+                    .as_deref()
+                    // if default enabled: the default expression provided or Default::default()
+                    .map(|d| d
+                        .clone()
+                        .explicit()
+                        .map(|d| d.value)
+                        .unwrap_or_else(|| parse_quote!(Default::default()))
+                    )
+                    // or just the field ident, as it will be provided on the function parameters
+                    .unwrap_or_else(|| parse_quote!(#field)),
+            )
+        }))
+        // every field explicitly ignored, with the default value
+        .extra_default_fields(match part {
+            Some(part) => derive.ignored_in_part(part),
+            None => derive.ignored(),
+        })
+        // any other ignored field, with the default value
+        .include_all_default(derive.ignore_extra.is_present() || derive.fill_default.is_present());
+
+    into_ty_fields_helper
+        .left_collector(|ix, f| f.other_member_for(derive_ty, ix))
+        .right_collector(|ix, f| {
+            let ident = if let Some(rename) = f.rename_for(derive_ty) {
+                rename.clone()
+            } else {
+                f.as_ident(ix)
+            };
+            // Fields mapped into many parts are cloned for all but the last one
+            let ident = match part {
+                Some(part) if f.targets_for(derive_ty).last() != Some(&part) => {
+                    field_binding(&ident, &part_idents(derive_ty)[part])
+                }
+                _ => ident,
+            };
+            f.build_into_for(false, is_try, &ident, derive)
+        })
+        .collect()
+}
+
+/// Collects every field of the other type along with its value (and the field of self mapped directly into it, if
+/// any) when deriving into it
+fn struct_into_fields<'f>(
+    derive: &ItemInput,
+    struct_fields: &'f Fields<FieldReceiver>,
+    is_try: bool,
+) -> Vec<(Option<&'f FieldReceiver>, syn::Ident, TokenStream)> {
+    let derive_ty = derive.path.as_ref();
+
+    // The other type has
+    let mut fields = struct_fields
+        .iter()
+        .enumerate()
+        // every field mapped directly, using the `with`
+        .filter(|(_, f)| f.maps_directly_for(false, derive_ty))
+        .map(|(ix, f)| {
+            let ident = if let Some(rename) = f.rename_for(derive_ty) {
+                rename.clone()
+            } else {
+                f.as_ident(ix)
+            };
+            let value = f.build_into_for(false, is_try, &ident, derive);
+            (Some(f), ident, value)
+        })
+        // every field flattened or split from self
+        .chain(
+            struct_fields
+                .iter()
+                .flat_map(|f| [f.flattened_fields_for(derive_ty), f.split_fields_for(derive_ty)])
+                .flatten()
+                .map(|(field, binding)| (None, field.clone(), quote!(#binding))),
+        )
+        .collect::<Vec<_>>();
+    // every additional field explicitly set
+    fields.extend(derive.add.iter().map(|a| {
+        let field = a.field.as_ref();
+        let value = a
+            .default
+            .as_deref()
+            // if default enabled: the default expression provided or Default::default()
+            .map(|d| {
+                d.clone()
+                    .explicit()
+                    .map(|d| d.value.to_token_stream())
+                    .unwrap_or_else(|| quote!(Default::default()))
+            })
+            // or just the field ident, as it will be provided on the function parameters
+            .unwrap_or_else(|| quote!(#field));
+        (None, field.clone(), value)
+    }));
+
+    fields
+}
+
+/// Builds the other type through its builder when deriving into it, calling one setter for each field
+fn struct_into_builder(
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+    builder: &syn::Expr,
+    build: &syn::Ident,
+) -> TokenStream {
+    let derive_ty = derive.path.as_ref();
+
+    // Every field of the other type is set with its setter (or the custom one)
+    let setters = struct_into_fields(derive, struct_fields, is_try)
+        .into_iter()
+        .map(|(f, field, value)| {
+            let setter = f.and_then(|f| f.setter_for(derive_ty)).unwrap_or(&field);
+            if f.is_some_and(|f| f.is_opt_for(derive_ty)) {
+                // optional fields are only set when present
+                quote!(let __builder = if let Some(__value) = #value { __builder.#setter(__value) } else { __builder };)
+            } else {
+                quote!(let __builder = __builder.#setter(#value);)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // The builder can be provided as a function or any expression, like a call or a mutable reference to it for
+    // builders whose setters take `&mut self`
+    let builder = if let syn::Expr::Path(_) = builder {
+        quote!(#builder())
+    } else {
+        quote!(#builder)
+    };
+
+    // Fallible builds are expected when deriving `try_into`
+    let build = if is_try {
+        quote!(__builder.#build()?)
+    } else {
+        quote!(__builder.#build())
+    };
+
+    quote!({
+        let __builder = #builder;
+        #( #setters )*
+        #build
+    })
+}
+
+/// Builds the call to the constructor of the other type when deriving into it, passing the fields as arguments
+fn struct_into_constructor(
+    derive: &ItemInput,
+    struct_fields: &Fields<FieldReceiver>,
+    is_try: bool,
+    constructor: &SpannedValue<ConstructorInput>,
+) -> TokenStream {
+    // The other type has every field, along with its value
+    let fields = struct_into_fields(derive, struct_fields, is_try);
+
+    // Every field must be passed to the constructor
+    for (_, field, _) in &fields {
+        if !constructor.args.contains(field) {
+            emit_error!(
+                constructor.span(),
+                "Field '{}' must be passed to the constructor",
+                field
+            );
+        }
+    }
+    let args = constructor
+        .args
+        .iter()
+        .map(|arg| match fields.iter().find(|(_, field, _)| field == arg) {
+            Some((_, _, value)) => value.clone(),
+            None => {
+                emit_error!(arg.span(), "There is no field '{}' to pass to the constructor", arg);
+                quote!(#arg)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Fallible constructors are expected when deriving `try_into`
+    let func = &constructor.func;
+    if is_try {
+        quote!(#func( #( #args ),* )?)
+    } else {
+        quote!(#func( #( #args ),* ))
+    }
+}
+
+/// Builds the statements binding the fields of the flattened and split ones when deriving into the other type
+fn struct_into_prelude(derive: &ItemInput, struct_fields: &Fields<FieldReceiver>, is_try: bool) -> Vec<TokenStream> {
+    let derive_ty = derive.path.as_ref();
+
+    struct_fields
+        .iter()
+        .flat_map(|f| {
+            [
+                f.build_flatten_into(is_try, derive_ty),
+                f.build_split(is_try, derive_ty),
+            ]
+        })
+        .collect()
+}
+
+/// Collects the additional fields without a default value, to be provided on custom functions when deriving into the
+/// other type
+fn struct_into_external_fields(derive: &ItemInput) -> Vec<TokenStream> {
+    derive
+        .add
+        .iter()
+        .filter(|a| a.default.is_none())
+        .map(|f| {
+            let ident = f.field.as_ref();
+            let ty = f.ty.as_ref().expect("'ty' must be provided").as_ref();
+            quote!(#ident: #ty)
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Implements a custom function on self returning a staged builder for its parameters (the additional fields without a
+/// default value), with a `finish` method only available once every one of them is set. The body is evaluated there,
+/// with self bound to `__from` along with every parameter.
+fn derive_params_builder(
+    vis: &syn::Visibility,
+    from_ty: &syn::Ident,
+    from_ty_generics: &syn::TypeGenerics,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    (fn_name, doc, output): (&syn::Ident, &str, TokenStream),
+    body: TokenStream,
+) -> TokenStream {
+    let builder_ty = format_ident!("{from_ty}{}Builder", fn_name.to_string().to_upper_camel_case());
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    // Every parameter is tracked by a type parameter, being `()` until set
+    let params = derive
+        .add
+        .iter()
+        .filter(|a| a.default.is_none())
+        .map(|a| {
+            let ident = a.field.as_ref();
+            let ty = a.ty.as_ref().expect("'ty' must be provided").as_ref();
+            let state = format_ident!("__{}", ident.to_string().to_upper_camel_case());
+            (ident, ty, state)
+        })
+        .collect::<Vec<_>>();
+    let param_idents = params.iter().map(|(ident, ..)| *ident).collect::<Vec<_>>();
+    let param_tys = params.iter().map(|(_, ty, _)| *ty).collect::<Vec<_>>();
+    let param_states = params.iter().map(|(.., state)| state).collect::<Vec<_>>();
+
+    // The generic arguments of the builder type, followed by the given states
+    let generic_args = generics
+        .params
+        .iter()
+        .map(|p| match p {
+            syn::GenericParam::Lifetime(l) => l.lifetime.to_token_stream(),
+            syn::GenericParam::Type(t) => t.ident.to_token_stream(),
+            syn::GenericParam::Const(c) => c.ident.to_token_stream(),
+        })
+        .collect::<Vec<_>>();
+    let builder_with = |states: Vec<TokenStream>| quote!(#builder_ty<#( #generic_args, )* #( #states ),*>);
+
+    // The generics of the builder type, along with the given states
+    let generics_with = |states: &[&syn::Ident]| {
+        let mut generics = generics.clone();
+        generics.params.extend(
+            states
+                .iter()
+                .map(|s| syn::GenericParam::from(syn::TypeParam::from((*s).clone()))),
+        );
+        generics
+    };
+
+    let builder_generics = generics_with(&param_states);
+    let (builder_impl_generics, ..) = builder_generics.split_for_impl();
+    let builder_doc = format!("Builder of the parameters of [{from_ty}::{fn_name}]");
+    let unset = builder_with(params.iter().map(|_| quote!(())).collect());
+    let finished = builder_with(param_tys.iter().map(|ty| ty.to_token_stream()).collect());
+
+    // Every setter is available only while its parameter is not set
+    let setters = params.iter().enumerate().map(|(ix, (ident, ty, _))| {
+        let other_states = param_states
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != ix)
+            .map(|(_, s)| *s)
+            .collect::<Vec<_>>();
+        let setter_generics = generics_with(&other_states);
+        let (setter_impl_generics, ..) = setter_generics.split_for_impl();
+        let states_with = |set: TokenStream| {
+            param_states
+                .iter()
+                .enumerate()
+                .map(|(i, s)| if i == ix { set.clone() } else { s.to_token_stream() })
+                .collect::<Vec<_>>()
+        };
+        let before = builder_with(states_with(quote!(())));
+        let after = builder_with(states_with(ty.to_token_stream()));
+        let other_idents = param_idents
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != ix)
+            .map(|(_, p)| *p)
+            .collect::<Vec<_>>();
+        let setter_doc = format!("Sets the `{ident}` parameter");
+        quote!(
+            #[automatically_derived]
+            impl #setter_impl_generics #before #where_clause {
+                #[doc = #setter_doc]
+                pub fn #ident(self, #ident: #ty) -> #after {
+                    #builder_ty {
+                        __from: self.__from,
+                        #( #other_idents: self.#other_idents, )*
+                        #ident,
+                        __marker: ::core::marker::PhantomData,
+                    }
+                }
+            }
+        )
+    });
+
+    quote!(
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder_ty #builder_impl_generics #where_clause {
+            __from: #from_ty #from_ty_generics,
+            #( #param_idents: #param_states, )*
+            __marker: ::core::marker::PhantomData<fn() -> #output>,
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #from_ty #from_ty_generics #where_clause {
+            #[doc = #doc]
+            pub fn #fn_name(self) -> #unset {
+                #builder_ty {
+                    __from: self,
+                    #( #param_idents: (), )*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #( #setters )*
+
+        #[automatically_derived]
+        #[allow(non_shorthand_field_patterns, non_snake_case)]
+        impl #impl_generics #finished #where_clause {
+            #[doc = #doc]
+            pub fn finish(self) -> #output {
+                let #builder_ty { __from, #( #param_idents, )* __marker: _ } = self;
+                #body
+            }
+        }
+    )
+}
+
+/// Computes the parameter name of every part of the other type, after the name of its type
+fn part_idents(derive_ty: &DeriveTy) -> Vec<syn::Ident> {
+    derive_ty
+        .parts()
+        .iter()
+        .map(|p| {
+            let last = p.path.segments.last().expect("at least one segment");
+            format_ident!("{}", last.ident.to_string().to_snake_case())
+        })
+        .collect()
+}
+
+/// Switches the unnamed fields of the variants mapped by member into the named style, so they're accessed through their
+/// positions (`Variant { 0: .. }`) and can be reshaped into fields of a different shape
+fn enum_variants_by_member<'v>(derive: &ItemInput, enum_variants: &'v [VariantReceiver]) -> Cow<'v, [VariantReceiver]> {
+    let derive_ty = derive.path.as_ref();
+    if enum_variants
+        .iter()
+        .any(|v| v.fields.is_tuple() && v.maps_by_member_for(derive_ty))
+    {
+        Cow::Owned(
+            enum_variants
+                .iter()
+                .map(|v| {
+                    let mut v = v.clone();
+                    if v.fields.is_tuple() && v.maps_by_member_for(derive_ty) {
+                        v.fields = Fields::new(Style::Struct, v.fields.fields);
+                    }
+                    v
+                })
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(enum_variants)
+    }
+}
+
+/// Collects the variants of the other enum mapped into many variants of self, told apart by their `split_by`
+/// predicates, when none of them is a fallback (without predicate) so they can fail if no predicate matches
+fn enum_split_fallbacks<'v>(
+    derive: &ItemInput,
+    enum_variants: &'v [VariantReceiver],
+    is_try: bool,
+) -> Vec<&'v syn::Ident> {
+    let derive_ty = derive.path.as_ref();
+
+    // Variants of self mapped from each variant of the other enum, in order
+    let mut mapped_from: Vec<(&syn::Ident, Vec<&VariantReceiver>)> = Vec::new();
+    for v in enum_variants.iter().filter(|v| v.skip_for(derive_ty).is_none()) {
+        for other in v.source_variants_for(derive_ty) {
+            match mapped_from.iter_mut().find(|(o, _)| *o == other) {
+                Some((_, variants)) => variants.push(v),
+                None => mapped_from.push((other, vec![v])),
+            }
+        }
+    }
+
+    let mut fallbacks = Vec::new();
+    for (other, variants) in mapped_from {
+        let Some((last, rest)) = variants.split_last() else {
+            continue;
+        };
+        // every variant but the last one must be told apart
+        for v in rest.iter().filter(|v| v.split_by_for(derive_ty).is_none()) {
+            emit_error!(
+                v.ident.span(),
+                "Variant '{}' of the other enum is mapped into many variants, all but the last one need a 'split_by'",
+                other
+            );
+        }
+        // if the last one is told apart as well, the other variant fails when no predicate matches
+        if last.split_by_for(derive_ty).is_some() {
+            if is_try {
+                fallbacks.push(other);
+            } else {
+                emit_error!(
+                    last.ident.span(),
+                    "The last variant mapped from '{}' can't have a 'split_by' on 'from' derives, use 'try_from' \
+                     instead",
+                    other
+                );
+            }
+        }
+    }
+
+    fallbacks
+}
+
+fn derive_enum_from(
+    from: &SpannedValue<Override<DeriveInput>>,
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    enum_variants: &[VariantReceiver],
+    is_try: bool,
+) -> TokenStream {
     // Derive from the other type into self
-    let original_from_ty = derive.path.as_ref();
+    let derive_ty = derive.path.as_ref();
+    let original_from_ty = derive_ty.single().expect("single type");
     let into_ty = ident;
     let (_, into_ty_generics, _) = generics.split_for_impl();
 
@@ -531,7 +1768,7 @@ fn derive_enum_from(
     // Process other_ty to append where clauses
     enum_variants.iter().for_each(|v| {
         v.fields.iter().for_each(|f| {
-            if let Some(other_ty) = f.other_ty_for(original_from_ty) {
+            if let Some(other_ty) = f.other_ty_for(derive_ty) {
                 let ty_ident = other_ty;
                 let resolved_ident = generics_rename_map.get(ty_ident).unwrap_or(ty_ident);
                 let field_ty = &f.ty;
@@ -551,7 +1788,7 @@ fn derive_enum_from(
     // The other type has
     let match_body = VariantsHelper::new(enum_variants)
         // every non-skipped variant of self
-        .filtering_variants(|v| v.skip_for(original_from_ty).is_none())
+        .filtering_variants(|v| v.skip_for(derive_ty).is_none())
         // every additional variant explicitly set
         .include_extra_variants(derive.add.iter().map(|i| {
             let field = i.field.as_ref();
@@ -570,45 +1807,63 @@ fn derive_enum_from(
                 ),
             )
         }))
+        // every variant explicitly ignored, with the default value
+        .include_extra_variants(derive.ignored().into_iter().map(|variant| {
+            (quote!(#from_ty::#variant { .. }), Some(quote!(Default::default())))
+        }))
         // any other variant ignored, if any
         .ignore_all_extra_variants(if derive.ignore_extra.is_present(){
             Some(quote!(Default::default()))
         } else {
             None
         })
-        // the left side of the match will be the from variant, along with its fields (if any)
+        // every other variant mapped into many variants of self but not matching any of them, failing
+        .include_extra_variants(enum_split_fallbacks(derive, enum_variants, is_try).into_iter().map(|variant| {
+            let msg = format!("No variant of '{into_ty}' matches '{variant}'");
+            (quote!(#from_ty::#variant { .. }), Some(quote!(return Err(::anyhow::anyhow!(#msg)))))
+        }))
+        // the left side of the match will be the from variants, along with their fields (if any)
         .left_collector(|v, fields| {
-            // the other type variant name will be the same name or the rename ident
-            let ident = if let Some(rename) = v.rename_for(original_from_ty) {
-                rename
-            } else {
-                &v.ident
-            };
+            // the other type variants will be the explicit ones or the same name (or the rename ident)
+            let idents = v.source_variants_for(derive_ty);
+            // told apart from other variants of self mapped from the same ones by the predicate, if any
+            let guard = v.split_by_for(derive_ty).map(|split_by| quote!(if #split_by));
+            // the member of the other type variant each field is mapped from
+            let members = v.other_members_for(derive_ty);
+            let additional = v.additional_for(derive_ty).map(|i| i.as_slice()).unwrap_or_default();
+            // the additional positional fields, ignored by their index unless every extra field is ignored
+            let positional = additional
+                .iter()
+                .filter(|_| !v.ignore_extra_for(derive_ty))
+                .filter_map(|i| i.index.as_deref().map(|index| syn::Index::from(*index)))
+                .collect::<Vec<_>>();
             // the other type variant has
             let from_fields = fields
                 // every none-skipped field of self variant
-                .filtering(|_ix, f| f.skip_for(original_from_ty).is_none())
+                .filtering(|_ix, f| f.skip_for(derive_ty).is_none())
                 // ignoring every additional field explicitly set
-                .extra_fields(v
-                    .additional_for(original_from_ty)
-                    .map(|i| i.iter().map(|i| i.field.as_ref()).collect::<Vec<_>>())
-                    .unwrap_or_default())
+                .extra_fields(additional.iter().filter(|i| i.index.is_none()).map(|i| i.field.as_ref()))
+                // ignoring every field explicitly ignored
+                .ignore_extra(v.ignored_for(derive_ty))
                 // and ignoring any other field, if set
-                .ignore_all_extra(v.ignore_extra_for(original_from_ty))
-                // where we collect each field ident (or the rename) deconstructed
-                .left_collector(|ix, f| {
-                    let ident = if let Some(rename) = f.rename_for(original_from_ty) {
-                        rename.clone()
-                    } else {
-                        f.as_ident(ix)
-                    };
-                    quote!(#ident)
-                })
+                .ignore_all_extra(v.ignore_extra_for(derive_ty))
+                // wrapped below along with the positional fields, if any
+                .include_wrapper(positional.is_empty())
+                // where we collect each field member deconstructed
+                .left_collector(move |ix, _f| members[ix].clone())
                 // as the field ident
                 .right_collector(FieldsCollector::ident)
                 .collect();
+            // unit variants of self ignoring every field of the other variant
+            let from_fields = if v.fields.is_unit() && v.ignore_extra_for(derive_ty) {
+                quote!({ .. })
+            } else if !positional.is_empty() {
+                quote!({ #( #positional: _, )* #from_fields })
+            } else {
+                from_fields
+            };
 
-            quote!( #from_ty::#ident #from_fields )
+            quote!( #( #from_ty::#idents #from_fields )|* #guard )
         })
         // the right side of the match will be the into variant, along with its fields (if any)
         .right_collector(|v, fields| {
@@ -616,12 +1871,12 @@ fn derive_enum_from(
             // Self type variant has
             let into_fields = fields
                 // every non-skipped field (as it's on the from)
-                .filtering(|_ix, f| f.skip_for(original_from_ty).is_none())
+                .filtering(|_ix, f| f.skip_for(derive_ty).is_none())
                 // skipped fields with the custom value provided
                 .extra_fields_with(
                     v.fields
                         .iter()
-                        .filter_map(|f| f.skip_for(original_from_ty).map(|skip| (f, skip)))
+                        .filter_map(|f| f.skip_for(derive_ty).map(|skip| (f, skip)))
                         .filter_map(|(f, skip)| {
                             f.ident.as_ref().map(|field| {
                                 (
@@ -638,7 +1893,7 @@ fn derive_enum_from(
                                             .unwrap_or_else(|| parse_quote!(Default::default())))
                                         // or just the field ident, as it will be provided on the function parameters
                                         .unwrap_or_else(|| {
-                                            let ident = if let Some(rename) = f.rename_for(original_from_ty)  {
+                                            let ident = if let Some(rename) = f.rename_for(derive_ty)  {
                                                 rename
                                             } else {
                                                 field
@@ -650,10 +1905,12 @@ fn derive_enum_from(
                             })
                         }),
                 )
+                // into the same member of self
+                .left_collector(|ix, f| f.self_member(ix))
                 // collecting the fields using the `with`
                 .right_collector(|ix, f| {
                     let ident = f.as_ident(ix);
-                    f.build_into_for(true, is_try, &ident, original_from_ty)
+                    f.build_into_for(true, is_try, &ident, derive)
                 })
                 .collect();
 
@@ -668,14 +1925,14 @@ fn derive_enum_from(
             .iter()
             .flat_map(|v| &v.fields.fields)
             .filter(|f| {
-                f.skip_for(original_from_ty)
+                f.skip_for(derive_ty)
                     .filter(|map| map.as_ref().explicit().map(|s| s.default.is_none()).unwrap_or(true))
                     .is_some()
             })
             .filter_map(|f| {
                 let ty = &f.ty;
                 f.ident.as_ref().map(|i| {
-                    let ident = if let Some(rename) = f.rename_for(original_from_ty) {
+                    let ident = if let Some(rename) = f.rename_for(derive_ty) {
                         rename
                     } else {
                         i
@@ -706,7 +1963,7 @@ fn derive_enum_from(
         if is_try {
             quote!(
                 #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
                 impl #impl_generics #into_ty #into_ty_generics #where_clause {
                     #[doc = #doc]
                     #[allow(clippy::too_many_arguments)]
@@ -719,7 +1976,7 @@ fn derive_enum_from(
         } else {
             quote!(
                 #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
                 impl #impl_generics #into_ty #into_ty_generics #where_clause {
                     #[doc = #doc]
                     #[allow(clippy::too_many_arguments)]
@@ -733,7 +1990,7 @@ fn derive_enum_from(
         // Implement the [TryFrom] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
             impl #impl_generics TryFrom<#from_ty_with_generics> for #into_ty #into_ty_generics #where_clause {
                 type Error = ::anyhow::Error;
 
@@ -748,7 +2005,7 @@ fn derive_enum_from(
         // Implement the [From] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
             impl #impl_generics From<#from_ty_with_generics> for #into_ty #into_ty_generics #where_clause {
                 fn from(other: #from_ty_with_generics) -> Self {
                     match other #match_body
@@ -768,7 +2025,8 @@ fn derive_enum_into(
 ) -> TokenStream {
     // Derive from self into the other type
     let from_ty = ident;
-    let original_into_ty = derive.path.as_ref();
+    let derive_ty = derive.path.as_ref();
+    let original_into_ty = derive_ty.single().expect("single type");
     let (_, from_ty_generics, _) = generics.split_for_impl();
 
     // Merge generics
@@ -778,7 +2036,7 @@ fn derive_enum_into(
     // Process other_ty to append where clauses
     enum_variants.iter().for_each(|v| {
         v.fields.iter().for_each(|f| {
-            if let Some(other_ty) = f.other_ty_for(original_into_ty) {
+            if let Some(other_ty) = f.other_ty_for(derive_ty) {
                 let ty_ident = other_ty;
                 let resolved_ident = generics_rename_map.get(ty_ident).unwrap_or(ty_ident);
                 let field_ty = &f.ty;
@@ -798,12 +2056,12 @@ fn derive_enum_into(
     // Self type has
     let match_body = VariantsHelper::new(enum_variants)
         // every non-skipped variant 
-        .filtering_variants(|v| v.skip_for(original_into_ty).is_none())
+        .filtering_variants(|v| v.skip_for(derive_ty).is_none())
         // and skipped variants ignored
         .include_extra_variants(
             enum_variants
                 .iter()
-                .filter_map(|v| v.skip_for(original_into_ty).map(|skip| (v, skip)))
+                .filter_map(|v| v.skip_for(derive_ty).map(|skip| (v, skip)))
                 .map(|(v, skip)| {
                     let variant = &v.ident;
                     (
@@ -829,9 +2087,11 @@ fn derive_enum_into(
             let ident = &v.ident;
             // Self variant has every field (whether it's used or not)
             let from_fields = fields
+                // from the same member of self
+                .left_collector(|ix, f| f.self_member(ix))
                 // collecting as the field ident (renamed)
                 .right_collector(|ix, f| {
-                    let ident = if let Some(rename) = f.rename_for(original_into_ty) {
+                    let ident = if let Some(rename) = f.rename_for(derive_ty) {
                         rename.clone()
                     } else {
                         f.as_ident(ix)
@@ -844,65 +2104,62 @@ fn derive_enum_into(
         })
         // the right side of the match will be the into variant, along with its fields (if any)
         .right_collector(|v, fields| {
-            // the other type variant name will be the same name or the rename ident
-            let ident = if let Some(rename) = v.rename_for(original_into_ty) {
-                rename
-            } else {
-                &v.ident
+            // the other type variant name will be the explicit one, the single one mapped from or the same name (or the
+            // rename ident)
+            let ident = v.target_variant_for(derive_ty);
+            // the value of every additional field explicitly set
+            let additional_value = |i: &AddInput| -> syn::Expr {
+                let field = i.field.as_ref();
+                i.default
+                    .as_deref()
+                    // if default enabled: the default expression provided or Default::default()
+                    .map(|d| d.clone().explicit().map(|d| d.value).unwrap_or_else(|| parse_quote!(Default::default())))
+                    // or just the field ident, as it will be provided on the function parameters
+                    .unwrap_or_else(|| {
+                        let field_provider = format_ident!("{field}_provider");
+                        parse_quote!(#field_provider())
+                    })
             };
+            let additional = v.additional_for(derive_ty).map(|i| i.as_slice()).unwrap_or_default();
+            // the member of the other type variant each field is mapped into
+            let members = v.other_members_for(derive_ty);
             // the other type variant has
             let into_fields = fields
                 // every non-skipped field
-                .filtering(|_ix, f| f.skip_for(original_into_ty).is_none())
+                .filtering(|_ix, f| f.skip_for(derive_ty).is_none())
                 // every additional field explicitly set
                 .extra_fields_with(
-                    v.additional_for(original_into_ty)
-                        .map(|i| {
-                            i.iter()
-                                .map(|i| {
-                                    let field = i.field.as_ref();
-                                    (
-                                        field,
-                                        // populated with
-                                        i.default
-                                            .as_deref()
-                                            // if default enabled: the default expression provided or Default::default()
-                                            .map(|d| d
-                                                .clone()
-                                                .explicit()
-                                                .map(|d| d.value)
-                                                .unwrap_or_else(|| parse_quote!(Default::default()))
-                                            )
-                                            // or just the field ident, as it will be provided on the function parameters
-                                            .unwrap_or_else(|| {
-                                                let field_provider = format_ident!("{field}_provider");
-                                                parse_quote!(#field_provider())
-                                            }),
-                                    )
-                                })
-                                .collect::<Vec<_>>()
-                        })
-                        .unwrap_or_default(),
+                    additional
+                        .iter()
+                        .filter(|i| i.index.is_none())
+                        .map(|i| (i.field.as_ref(), additional_value(i))),
                 )
-                // where we collect each field ident (or the rename)
-                .left_collector(|ix, f| {
-                    let ident = if let Some(rename) = f.rename_for(original_into_ty) {
-                        rename.clone()
-                    } else {
-                        f.as_ident(ix)
-                    };
-                    quote!(#ident)
-                })
+                // every field explicitly ignored, with the default value
+                .extra_default_fields(v.ignored_for(derive_ty))
+                // where we collect each field member
+                .left_collector(move |ix, _f| members[ix].clone())
                 // using the `with`
                 .right_collector(|ix, f| {
-                    let ident = if let Some(rename) = f.rename_for(original_into_ty) {
+                    let ident = if let Some(rename) = f.rename_for(derive_ty) {
                         rename.clone()
                     } else {
                         f.as_ident(ix)
                     };
-                    f.build_into_for(false, is_try, &ident, original_into_ty)
-                })
-                .collect();
+                    f.build_into_for(false, is_try, &ident, derive)
+                });
+
+            // every additional positional field explicitly set, at its position
+            let positional = additional
+                .iter()
+                .filter_map(|i| i.index.as_deref().map(|index| (syn::Index::from(*index), additional_value(i))))
+                .map(|(index, value)| quote!(#index: #value))
+                .collect::<Vec<_>>();
+            let into_fields = if positional.is_empty() {
+                into_fields.collect()
+            } else {
+                let into_fields = into_fields.include_wrapper(false).collect();
+                quote!({ #( #positional, )* #into_fields })
+            };
 
             quote!( #into_ty::#ident #into_fields )
         })
@@ -913,7 +2170,7 @@ fn derive_enum_into(
         // Collect the additional fields that doesn't have a default value
         let external_fields = enum_variants
             .iter()
-            .filter_map(|v| v.additional_for(original_into_ty))
+            .filter_map(|v| v.additional_for(derive_ty))
             .flatten()
             .filter(|a| a.default.is_none())
             .map(|f| {
@@ -944,7 +2201,7 @@ fn derive_enum_into(
         if is_try {
             quote!(
                 #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
                 impl #impl_generics #from_ty #from_ty_generics #where_clause {
                     #[doc = #doc]
                     #[allow(clippy::too_many_arguments)]
@@ -957,7 +2214,7 @@ fn derive_enum_into(
         } else {
             quote!(
                 #[automatically_derived]
-                #[allow(non_shorthand_field_patterns)]
+                #[allow(non_shorthand_field_patterns, non_snake_case)]
                 impl #impl_generics #from_ty #from_ty_generics #where_clause {
                     #[doc = #doc]
                     #[allow(clippy::too_many_arguments)]
@@ -971,7 +2228,7 @@ fn derive_enum_into(
         // Implement the [TryFrom] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
             impl #impl_generics TryFrom<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
                 type Error = ::anyhow::Error;
 
@@ -986,7 +2243,7 @@ fn derive_enum_into(
         // Implement the [From] trait
         quote!(
             #[automatically_derived]
-            #[allow(non_shorthand_field_patterns)]
+            #[allow(non_shorthand_field_patterns, non_snake_case)]
             impl #impl_generics From<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
                 fn from(other: #from_ty #from_ty_generics) -> Self {
                     match other #match_body
@@ -996,6 +2253,602 @@ fn derive_enum_into(
     }
 }
 
+fn derive_via(ident: &syn::Ident, generics: &syn::Generics, derive: &ItemInput, via: &syn::TypePath) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    // Derive `From`
+    if derive.from.is_some() {
+        derive_via_from(ident, generics, derive, via, false).to_tokens(&mut output);
+    }
+
+    // Derive reverse `From`
+    if derive.into.is_some() {
+        derive_via_into(ident, generics, derive, via, false).to_tokens(&mut output);
+    }
+
+    // Derive `TryFrom`
+    if derive.try_from.is_some() {
+        derive_via_from(ident, generics, derive, via, true).to_tokens(&mut output);
+    }
+
+    // Derive reverse `TryFrom`
+    if derive.try_into.is_some() {
+        derive_via_into(ident, generics, derive, via, true).to_tokens(&mut output);
+    }
+
+    output
+}
+
+fn derive_via_from(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    via: &syn::TypePath,
+    is_try: bool,
+) -> TokenStream {
+    // Derive from the other type into self, through the intermediate type
+    let derive_ty = derive.path.as_ref();
+    let original_from_ty = derive_ty.single().expect("single type");
+    let into_ty = ident;
+    let (_, into_ty_generics, _) = generics.split_for_impl();
+
+    // Merge generics
+    let (mut all_generics, from_ty_with_generics, _) = process_generics(generics, original_from_ty, true);
+
+    // Both conversions must be implemented
+    let where_clause = all_generics.make_where_clause();
+    if is_try {
+        where_clause
+            .predicates
+            .push(parse_quote!(#via: TryFrom<#from_ty_with_generics>));
+        where_clause
+            .predicates
+            .push(parse_quote!(<#via as TryFrom<#from_ty_with_generics>>::Error: Into<::anyhow::Error>));
+        where_clause
+            .predicates
+            .push(parse_quote!(#into_ty #into_ty_generics: TryFrom<#via>));
+        where_clause
+            .predicates
+            .push(parse_quote!(<#into_ty #into_ty_generics as TryFrom<#via>>::Error: Into<::anyhow::Error>));
+    } else {
+        where_clause
+            .predicates
+            .push(parse_quote!(#via: From<#from_ty_with_generics>));
+        where_clause
+            .predicates
+            .push(parse_quote!(#into_ty #into_ty_generics: From<#via>));
+    }
+
+    // Split generics for impl and where
+    let (impl_generics, _, where_clause) = all_generics.split_for_impl();
+
+    if is_try {
+        // Implement the [TryFrom] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics TryFrom<#from_ty_with_generics> for #into_ty #into_ty_generics #where_clause {
+                type Error = ::anyhow::Error;
+
+                fn try_from(from: #from_ty_with_generics)
+                    -> ::std::result::Result<Self, <Self as TryFrom<#from_ty_with_generics>>::Error> {
+                    let via: #via = TryFrom::try_from(from).map_err(Into::<::anyhow::Error>::into)?;
+                    <Self as TryFrom<#via>>::try_from(via).map_err(Into::into)
+                }
+            }
+        )
+    } else {
+        // Implement the [From] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics From<#from_ty_with_generics> for #into_ty #into_ty_generics #where_clause {
+                fn from(from: #from_ty_with_generics) -> Self {
+                    let via: #via = From::from(from);
+                    <Self as From<#via>>::from(via)
+                }
+            }
+        )
+    }
+}
+
+fn derive_via_into(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    derive: &ItemInput,
+    via: &syn::TypePath,
+    is_try: bool,
+) -> TokenStream {
+    // Derive from self into the other type, through the intermediate type
+    let from_ty = ident;
+    let derive_ty = derive.path.as_ref();
+    let original_into_ty = derive_ty.single().expect("single type");
+    let (_, from_ty_generics, _) = generics.split_for_impl();
+
+    // Merge generics
+    let (mut all_generics, into_ty_with_generics, _) = process_generics(generics, original_into_ty, false);
+
+    // Both conversions must be implemented
+    let where_clause = all_generics.make_where_clause();
+    if is_try {
+        where_clause
+            .predicates
+            .push(parse_quote!(#via: TryFrom<#from_ty #from_ty_generics>));
+        where_clause
+            .predicates
+            .push(parse_quote!(<#via as TryFrom<#from_ty #from_ty_generics>>::Error: Into<::anyhow::Error>));
+        where_clause
+            .predicates
+            .push(parse_quote!(#into_ty_with_generics: TryFrom<#via>));
+        where_clause
+            .predicates
+            .push(parse_quote!(<#into_ty_with_generics as TryFrom<#via>>::Error: Into<::anyhow::Error>));
+    } else {
+        where_clause
+            .predicates
+            .push(parse_quote!(#via: From<#from_ty #from_ty_generics>));
+        where_clause
+            .predicates
+            .push(parse_quote!(#into_ty_with_generics: From<#via>));
+    }
+
+    // Split generics for impl and where
+    let (impl_generics, _, where_clause) = all_generics.split_for_impl();
+
+    if is_try {
+        // Implement the [TryFrom] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics TryFrom<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
+                type Error = ::anyhow::Error;
+
+                fn try_from(from: #from_ty #from_ty_generics)
+                    -> ::std::result::Result<Self, <Self as TryFrom<#from_ty #from_ty_generics>>::Error> {
+                    let via: #via = TryFrom::try_from(from).map_err(Into::<::anyhow::Error>::into)?;
+                    <Self as TryFrom<#via>>::try_from(via).map_err(Into::into)
+                }
+            }
+        )
+    } else {
+        // Implement the [From] trait
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics From<#from_ty #from_ty_generics> for #into_ty_with_generics #where_clause {
+                fn from(from: #from_ty #from_ty_generics) -> Self {
+                    let via: #via = From::from(from);
+                    <Self as From<#via>>::from(via)
+                }
+            }
+        )
+    }
+}
+
+/// Validates the `str` derive and the variant aliases, emitting errors if not well formed
+fn validate_str(str_item: Option<&SpannedValue<StrInput>>, data: &Data<VariantReceiver, FieldReceiver>) {
+    let Data::Enum(enum_variants) = data else {
+        if let Some(str_item) = str_item {
+            emit_error!(str_item.span(), "The 'str' derive is only supported for enums");
+        }
+        return;
+    };
+    let Some(str_item) = str_item else {
+        for alias in enum_variants.iter().flat_map(|v| &v.alias) {
+            emit_error!(alias.span(), "'alias' requires a 'str' derive");
+        }
+        return;
+    };
+    // Every variant must be a unit one, with a distinct name
+    let mut names = HashSet::new();
+    for variant in enum_variants {
+        if !variant.fields.is_empty() {
+            emit_error!(variant.ident.span(), "The 'str' derive only supports unit variants");
+        }
+        let name = variant.str_name(str_item.case.as_deref());
+        for (name, span) in std::iter::once((name, variant.ident.span()))
+            .chain(variant.alias.iter().map(|a| (a.as_ref().clone(), a.span())))
+        {
+            let key = if str_item.case_insensitive.is_present() {
+                name.to_ascii_lowercase()
+            } else {
+                name
+            };
+            if !names.insert(key) {
+                emit_error!(span, "This name is duplicated");
+            }
+        }
+    }
+}
+
+fn derive_str(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    str_item: &StrInput,
+    enum_variants: &[VariantReceiver],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let crate_name = model_mapper_crate();
+    let ty_name = ident.to_string();
+
+    // The name of every variant, along with its aliases
+    let variant_idents = enum_variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let names = enum_variants
+        .iter()
+        .map(|v| v.str_name(str_item.case.as_deref()))
+        .collect::<Vec<_>>();
+    let all_names = enum_variants
+        .iter()
+        .zip(&names)
+        .map(|(v, name)| {
+            std::iter::once(name.clone())
+                .chain(v.alias.iter().map(|a| a.as_ref().clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Match the given string, with or without its case
+    let parse_arms = variant_idents.iter().zip(&all_names).map(|(variant, names)| {
+        if str_item.case_insensitive.is_present() {
+            quote!(s if #( s.eq_ignore_ascii_case(#names) )||* => Ok(Self::#variant))
+        } else {
+            quote!(#( #names )|* => Ok(Self::#variant))
+        }
+    });
+
+    quote!(
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns the string representation of the variant
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #( Self::#variant_idents => #names, )*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = #crate_name::ParseEnumError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    #( #parse_arms, )*
+                    _ => Err(#crate_name::ParseEnumError::new(#ty_name)),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::TryFrom<&str> for #ident #ty_generics #where_clause {
+            type Error = #crate_name::ParseEnumError;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(s)
+            }
+        }
+    )
+}
+
+/// Validates the `int` derive and the variant codes, emitting errors if not well formed
+fn validate_int(int_item: Option<&SpannedValue<IntInput>>, data: &Data<VariantReceiver, FieldReceiver>) {
+    let Data::Enum(enum_variants) = data else {
+        if let Some(int_item) = int_item {
+            emit_error!(int_item.span(), "The 'int' derive is only supported for enums");
+        }
+        return;
+    };
+    let Some(int_item) = int_item else {
+        for code in enum_variants.iter().filter_map(|v| v.code.as_ref()) {
+            emit_error!(code.span(), "'code' requires an 'int' derive");
+        }
+        return;
+    };
+    // The fallback must be a variant of the enum
+    let fallback = int_item.fallback.as_ref();
+    if let Some(fallback) = fallback
+        && !enum_variants.iter().any(|v| &v.ident == fallback.as_ref())
+    {
+        emit_error!(fallback.span(), "There is no variant '{}'", fallback.as_ref());
+    }
+    // Every variant must be a unit one, except for the fallback which can keep the unknown value
+    for variant in enum_variants {
+        let is_fallback = fallback.is_some_and(|f| f.as_ref() == &variant.ident);
+        if is_fallback && variant.fields.is_tuple() && variant.fields.len() == 1 {
+            if let Some(code) = variant.code.as_ref() {
+                emit_error!(
+                    code.span(),
+                    "The fallback variant keeping the unknown value can't have a 'code'"
+                );
+            }
+        } else if !variant.fields.is_empty() {
+            emit_error!(
+                variant.ident.span(),
+                "The 'int' derive only supports unit variants, besides a fallback with a single unnamed field"
+            );
+        }
+    }
+    // Codes must be unique, which can only be checked for those given (or inferred from) integer literals
+    let mut codes = HashSet::new();
+    let mut last_discriminant = Some(-1i128);
+    for variant in enum_variants {
+        let discriminant = match variant.discriminant.as_ref() {
+            Some(discriminant) => int_literal(discriminant),
+            None => last_discriminant.and_then(|d| d.checked_add(1)),
+        };
+        last_discriminant = discriminant;
+        if fallback.is_some_and(|f| f.as_ref() == &variant.ident) && !variant.fields.is_empty() {
+            continue;
+        }
+        let (code, span) = match variant.code.as_ref() {
+            Some(code) => (int_literal(code), code.span()),
+            None => (discriminant, variant.ident.span()),
+        };
+        if let Some(code) = code
+            && !codes.insert(code)
+        {
+            emit_error!(span, "The code {} is duplicated", code);
+        }
+    }
+}
+
+/// The value of the given integer literal (optionally negated), if it's one
+fn int_literal(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => int_literal(expr).map(|i| -i),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) | syn::Expr::Group(syn::ExprGroup { expr, .. }) => {
+            int_literal(expr)
+        }
+        _ => None,
+    }
+}
+
+fn derive_int(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    int_item: &IntInput,
+    enum_variants: &[VariantReceiver],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let crate_name = model_mapper_crate();
+    let int_ty = int_item.ty();
+    let ty_name = ident.to_string();
+    let fallback = int_item.fallback.as_deref();
+
+    // Compute the code of every variant, following the discriminant rules when not provided: the first one is zero and
+    // each one is the previous plus one
+    let mut last_discriminant = (quote!(0), 0usize);
+    let mut codes = Vec::new();
+    for (ix, variant) in enum_variants.iter().enumerate() {
+        let discriminant = if let Some(discriminant) = variant.discriminant.as_ref() {
+            last_discriminant = (discriminant.to_token_stream(), 0);
+            discriminant.to_token_stream()
+        } else if ix == 0 {
+            quote!(0)
+        } else {
+            last_discriminant.1 += 1;
+            let (last, offset) = &last_discriminant;
+            let offset = proc_macro2::Literal::usize_unsuffixed(*offset);
+            quote!((#last) + #offset)
+        };
+        let code = variant
+            .code
+            .as_deref()
+            .map(|c| c.to_token_stream())
+            .unwrap_or(discriminant);
+        codes.push((&variant.ident, code, !variant.fields.is_empty()));
+    }
+
+    // Map every variant into its code, or the value kept by the fallback
+    let into_arms = codes.iter().map(|(variant, code, keeps_value)| {
+        if *keeps_value {
+            quote!(#ident::#variant(value) => value)
+        } else {
+            quote!(#ident::#variant => #code)
+        }
+    });
+
+    // Map every code into its variant
+    let from_arms = codes
+        .iter()
+        .filter(|(_, _, keeps_value)| !keeps_value)
+        .map(|(variant, code, _)| quote!(value if value == (#code) => Self::#variant))
+        .collect::<Vec<_>>();
+
+    let into_impl = quote!(
+        #[automatically_derived]
+        impl #impl_generics From<#ident #ty_generics> for #int_ty #where_clause {
+            fn from(from: #ident #ty_generics) -> Self {
+                match from {
+                    #( #into_arms, )*
+                }
+            }
+        }
+    );
+
+    let from_impl = if let Some(fallback) = fallback {
+        let keeps_value = codes
+            .iter()
+            .any(|(variant, _, keeps_value)| *variant == fallback && *keeps_value);
+        let unknown = if keeps_value {
+            quote!(value => Self::#fallback(value))
+        } else {
+            quote!(_ => Self::#fallback)
+        };
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics From<#int_ty> for #ident #ty_generics #where_clause {
+                fn from(value: #int_ty) -> Self {
+                    match value {
+                        #( #from_arms, )*
+                        #unknown,
+                    }
+                }
+            }
+        )
+    } else {
+        quote!(
+            #[automatically_derived]
+            impl #impl_generics TryFrom<#int_ty> for #ident #ty_generics #where_clause {
+                type Error = #crate_name::ParseEnumError;
+
+                fn try_from(value: #int_ty) -> ::core::result::Result<Self, Self::Error> {
+                    Ok(match value {
+                        #( #from_arms, )*
+                        _ => return Err(#crate_name::ParseEnumError::new(#ty_name)),
+                    })
+                }
+            }
+        )
+    };
+
+    quote!(
+        #into_impl
+        #from_impl
+    )
+}
+
+fn derive_upgrades(ident: &syn::Ident, vis: &syn::Visibility, upgrade: &UpgradeInput) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    // Every version is upgraded to the previous one (more recent) first, and then to self
+    for (previous, version) in upgrade.versions.iter().zip(upgrade.versions.iter().skip(1)) {
+        let previous = previous.as_ref();
+        let version = version.as_ref();
+        if upgrade.fallible {
+            // Implement the [TryFrom] trait
+            quote!(
+                #[automatically_derived]
+                impl TryFrom<#version> for #ident {
+                    type Error = ::anyhow::Error;
+
+                    fn try_from(from: #version) -> ::std::result::Result<Self, <Self as TryFrom<#version>>::Error> {
+                        let previous: #previous = TryFrom::try_from(from).map_err(Into::<::anyhow::Error>::into)?;
+                        <Self as TryFrom<#previous>>::try_from(previous).map_err(Into::into)
+                    }
+                }
+            )
+            .to_tokens(&mut output);
+        } else {
+            // Implement the [From] trait
+            quote!(
+                #[automatically_derived]
+                impl From<#version> for #ident {
+                    fn from(from: #version) -> Self {
+                        let previous: #previous = From::from(from);
+                        <Self as From<#previous>>::from(previous)
+                    }
+                }
+            )
+            .to_tokens(&mut output);
+        }
+    }
+
+    // Generate the versioned enum wrapper, if requested
+    if let Some(wrapper) = upgrade.wrapper.as_ref() {
+        let versions = upgrade
+            .versions
+            .iter()
+            .map(|v| v.as_ref().path.clone())
+            .collect::<Vec<_>>();
+        let all_versions = std::iter::once(syn::Path::from(ident.clone()))
+            .chain(versions.iter().cloned())
+            .collect::<Vec<_>>();
+        let variants = version_variants(&all_versions);
+        let (latest_variant, older_variants) = variants.split_first().expect("at least one version");
+
+        let doc = format!("Any version of [{ident}], which can be upgraded to the latest one");
+        let upgrade_doc = format!("Upgrades the value to the latest version: [{ident}]");
+        let upgrade_fn = if upgrade.fallible {
+            quote!(
+                #[doc = #upgrade_doc]
+                pub fn upgrade(self) -> ::std::result::Result<#ident, ::anyhow::Error> {
+                    match self {
+                        Self::#latest_variant(latest) => Ok(latest),
+                        #( Self::#older_variants(older) => TryFrom::try_from(older).map_err(Into::into), )*
+                    }
+                }
+            )
+        } else {
+            quote!(
+                #[doc = #upgrade_doc]
+                pub fn upgrade(self) -> #ident {
+                    match self {
+                        Self::#latest_variant(latest) => latest,
+                        #( Self::#older_variants(older) => From::from(older), )*
+                    }
+                }
+            )
+        };
+
+        quote!(
+            #[doc = #doc]
+            #vis enum #wrapper {
+                #( #variants(#all_versions), )*
+            }
+
+            #[automatically_derived]
+            impl #wrapper {
+                #upgrade_fn
+            }
+
+            #(
+                #[automatically_derived]
+                impl From<#all_versions> for #wrapper {
+                    fn from(value: #all_versions) -> Self {
+                        Self::#variants(value)
+                    }
+                }
+            )*
+        )
+        .to_tokens(&mut output);
+    }
+
+    output
+}
+
+/// Computes the variant names for each version, stripping the common prefix of all of them (like `ConfigV1` and
+/// `ConfigV2` into `V1` and `V2`)
+fn version_variants(versions: &[syn::Path]) -> Vec<syn::Ident> {
+    let names = versions
+        .iter()
+        .map(|v| v.segments.last().expect("non-empty path").ident.to_string())
+        .collect::<Vec<_>>();
+    // Compute the common prefix, up to an uppercase char
+    let first = &names[0];
+    let mut prefix_len = names
+        .iter()
+        .skip(1)
+        .map(|n| first.chars().zip(n.chars()).take_while(|(a, b)| a == b).count())
+        .min()
+        .unwrap_or(0);
+    while prefix_len > 0 && !first.chars().nth(prefix_len).is_some_and(|c| c.is_uppercase()) {
+        prefix_len -= 1;
+    }
+    names
+        .iter()
+        .map(|n| {
+            let stripped = n.chars().skip(prefix_len).collect::<String>();
+            if prefix_len > 0 && names.iter().all(|o| o.chars().count() > prefix_len) {
+                format_ident!("{stripped}")
+            } else {
+                format_ident!("{n}")
+            }
+        })
+        .collect()
+}
+
 fn process_generics(
     target_generics: &syn::Generics,
     source_ty: &syn::TypePath,
@@ -102,3 +102,87 @@ impl<'a> Fold for TypePathReplacer<'a> {
         syn::fold::fold_type_path(self, i)
     }
 }
+
+/// The other type of a derive, either a single type, a tuple of types or a plain tuple type
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DeriveTy {
+    Single(TypePathWrapper),
+    Tuple(Vec<TypePathWrapper>),
+    TupleType(syn::TypeTuple),
+}
+
+impl DeriveTy {
+    /// Retrieves the type path, if it's a single type
+    pub(crate) fn single(&self) -> Option<&TypePath> {
+        match self {
+            DeriveTy::Single(ty) => Some(ty),
+            DeriveTy::Tuple(_) | DeriveTy::TupleType(_) => None,
+        }
+    }
+
+    /// Retrieves every type of the tuple, or the single type
+    pub(crate) fn parts(&self) -> Vec<&TypePath> {
+        match self {
+            DeriveTy::Single(ty) => vec![ty],
+            DeriveTy::Tuple(tys) => tys.iter().map(|t| &t.0).collect(),
+            DeriveTy::TupleType(_) => Vec::new(),
+        }
+    }
+
+    /// Retrieves the plain tuple type, if it's one
+    pub(crate) fn tuple_type(&self) -> Option<&syn::TypeTuple> {
+        match self {
+            DeriveTy::TupleType(ty) => Some(ty),
+            DeriveTy::Single(_) | DeriveTy::Tuple(_) => None,
+        }
+    }
+
+    /// Whether it's a plain tuple type with the given type as one of its elements, probably meant as a tuple of types
+    pub(crate) fn has_element(&self, ty: &TypePath) -> bool {
+        self.tuple_type()
+            .is_some_and(|t| t.elems.iter().any(|e| matches!(e, syn::Type::Path(e) if e == ty)))
+    }
+
+    fn from_type(ty: syn::Type) -> darling::Result<Self> {
+        match ty {
+            syn::Type::Path(ty) => Ok(DeriveTy::Single(TypePathWrapper(ty))),
+            syn::Type::Tuple(tuple) if !tuple.elems.is_empty() => Ok(DeriveTy::TupleType(tuple)),
+            syn::Type::Paren(ty) => Self::from_type(*ty.elem),
+            ty => Err(darling::Error::custom("Expected a type path or a tuple type").with_span(&ty)),
+        }
+    }
+}
+
+impl FromMeta for DeriveTy {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        if let syn::Lit::Str(s) = value {
+            Self::from_type(s.parse().map_err(darling::Error::custom)?)
+        } else {
+            Err(darling::Error::unexpected_lit_type(value))
+        }
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Tuple(tuple) if tuple.elems.len() > 1 => tuple
+                .elems
+                .iter()
+                .map(TypePathWrapper::from_expr)
+                .collect::<darling::Result<Vec<_>>>()
+                .map(DeriveTy::Tuple),
+            syn::Expr::Paren(paren) => Self::from_expr(&paren.expr),
+            syn::Expr::Lit(lit) => Self::from_value(&lit.lit),
+            _ => TypePathWrapper::from_expr(expr).map(DeriveTy::Single),
+        }
+    }
+}
+
+impl quote::ToTokens for DeriveTy {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            DeriveTy::Single(ty) => ty.to_tokens(tokens),
+            DeriveTy::Tuple(tys) => quote::quote!(( #( #tys ),* )).to_tokens(tokens),
+            DeriveTy::TupleType(ty) => ty.to_tokens(tokens),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use std::collections::HashMap;
+
+use model_mapper::{Adapter, Mapper, TryAdapter};
+
+// Adapters can be shipped by libraries as reusable conversion policies
+pub struct CentsDecimal;
+
+impl Adapter<i64, f64> for CentsDecimal {
+    fn adapt(value: i64) -> f64 {
+        value as f64 / 100.0
+    }
+}
+
+impl Adapter<f64, i64> for CentsDecimal {
+    fn adapt(value: f64) -> i64 {
+        (value * 100.0).round() as i64
+    }
+}
+
+impl TryAdapter<f64, i64> for CentsDecimal {
+    type Error = anyhow::Error;
+
+    fn try_adapt(value: f64) -> Result<i64, Self::Error> {
+        if value.is_finite() {
+            Ok(Self::adapt(value))
+        } else {
+            anyhow::bail!("Non-finite value")
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Foo {
+    pub total: i64,
+    pub lines: Vec<i64>,
+    pub taxes: HashMap<String, i64>,
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(from, into, ty = Foo)]
+pub struct Bar {
+    // The adapter implementation is picked based on the direction
+    #[mapper(via_adapter = CentsDecimal)]
+    pub total: f64,
+    // And it composes with other hints
+    #[mapper(iter(via_adapter = CentsDecimal))]
+    pub lines: Vec<f64>,
+    #[mapper(map(via_adapter = CentsDecimal))]
+    pub taxes: HashMap<String, f64>,
+}
+
+// For fallible derives, the `TryAdapter` is used instead
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(try_into, ty = Foo)]
+pub struct TryBar {
+    #[mapper(via_adapter = CentsDecimal)]
+    pub total: f64,
+    #[mapper(iter(via_adapter = CentsDecimal))]
+    pub lines: Vec<f64>,
+    #[mapper(map(via_adapter = CentsDecimal))]
+    pub taxes: HashMap<String, f64>,
+}
+// impl TryFrom<TryBar> for Foo {
+//     type Error = anyhow::Error;
+//
+//     fn try_from(from: TryBar) -> Result<Self, Self::Error> {
+//         let TryBar { total, lines, taxes } = from;
+//         Ok(Self {
+//             total: <CentsDecimal as TryAdapter<_, _>>::try_adapt(total)?,
+//             ..
+//         })
+//     }
+// }
+
+fn main() {
+    let foo = Foo {
+        total: 1050,
+        lines: vec![1000, 50],
+        taxes: HashMap::from([("vat".to_string(), 21)]),
+    };
+
+    let bar = Bar::from(foo.clone());
+    assert_eq!(bar.total, 10.5);
+    assert_eq!(bar.lines, vec![10.0, 0.5]);
+    assert_eq!(bar.taxes.get("vat"), Some(&0.21));
+
+    let back: Foo = bar.into();
+    assert_eq!(back, foo);
+
+    let invalid = TryBar {
+        total: f64::INFINITY,
+        lines: vec![],
+        taxes: HashMap::new(),
+    };
+    assert!(Foo::try_from(invalid).is_err());
+}
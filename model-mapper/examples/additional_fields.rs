@@ -70,6 +70,20 @@ struct Bar4 {
     field2: i64,
 }
 
+#[derive(Mapper)]
+#[mapper(
+    // When there are many of them, a staged builder can provide them by name instead
+    into(custom, params_builder),
+    try_into(custom, params_builder),
+    ty = Foo,
+    add(field = field3, ty = i64),
+    add(field = field4, ty = "Option<String>"),
+)]
+struct Bar5 {
+    field1: String,
+    field2: i64,
+}
+
 enum FooEnum {
     One,
     Two,
@@ -133,4 +147,19 @@ fn main() {
     };
     let mapped = bar.into_foo(None);
     assert_eq!(expected, mapped);
+
+    let bar = Bar5 {
+        field1: "val".into(),
+        field2: 2,
+    };
+    // The 'finish' method is only available once every field is set, in any order
+    let mapped = bar.into_foo().field4(None).field3(1).finish();
+    assert_eq!(expected, mapped);
+
+    let bar = Bar5 {
+        field1: "val".into(),
+        field2: 2,
+    };
+    let mapped = bar.try_into_foo().field3(1).field4(None).finish().unwrap();
+    assert_eq!(expected, mapped);
 }
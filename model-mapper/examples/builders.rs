@@ -0,0 +1,276 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+mod sdk {
+    /// A type only constructible through its builder, like the ones provided by SDK clients
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        name: String,
+        age: u32,
+        nickname: Option<String>,
+        retries: u8,
+    }
+
+    impl Request {
+        pub fn builder() -> RequestBuilder {
+            RequestBuilder::default()
+        }
+
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        pub fn age(&self) -> u32 {
+            self.age
+        }
+
+        pub fn nickname(&self) -> Option<&str> {
+            self.nickname.as_deref()
+        }
+
+        pub fn retries(&self) -> u8 {
+            self.retries
+        }
+    }
+
+    #[derive(Default)]
+    pub struct RequestBuilder {
+        name: Option<String>,
+        age: Option<u32>,
+        nickname: Option<String>,
+        retries: Option<u8>,
+    }
+
+    impl RequestBuilder {
+        pub fn name(mut self, name: String) -> Self {
+            self.name = Some(name);
+            self
+        }
+
+        pub fn age(mut self, age: u32) -> Self {
+            self.age = Some(age);
+            self
+        }
+
+        pub fn with_nickname(mut self, nickname: String) -> Self {
+            self.nickname = Some(nickname);
+            self
+        }
+
+        pub fn retries(mut self, retries: u8) -> Self {
+            self.retries = Some(retries);
+            self
+        }
+
+        pub fn build(self) -> Request {
+            Request {
+                name: self.name.unwrap_or_default(),
+                age: self.age.unwrap_or_default(),
+                nickname: self.nickname,
+                retries: self.retries.unwrap_or(3),
+            }
+        }
+
+        pub fn try_build(self) -> Result<Request, std::fmt::Error> {
+            match (self.name, self.age) {
+                (Some(name), Some(age)) => Ok(Request {
+                    name,
+                    age,
+                    nickname: self.nickname,
+                    retries: self.retries.unwrap_or(3),
+                }),
+                _ => Err(std::fmt::Error),
+            }
+        }
+    }
+
+    /// A type whose builder setters take it by mutable reference
+    #[derive(Debug, Clone)]
+    pub struct Config {
+        verbose: bool,
+        level: Option<u8>,
+    }
+
+    impl Config {
+        pub fn builder() -> ConfigBuilder {
+            ConfigBuilder::default()
+        }
+
+        pub fn verbose(&self) -> bool {
+            self.verbose
+        }
+
+        pub fn level(&self) -> Option<u8> {
+            self.level
+        }
+    }
+
+    #[derive(Default)]
+    pub struct ConfigBuilder {
+        verbose: bool,
+        level: Option<u8>,
+    }
+
+    impl ConfigBuilder {
+        pub fn verbose(&mut self, verbose: bool) -> &mut Self {
+            self.verbose = verbose;
+            self
+        }
+
+        pub fn level(&mut self, level: u8) -> &mut Self {
+            self.level = Some(level);
+            self
+        }
+
+        pub fn build(&self) -> Config {
+            Config {
+                verbose: self.verbose,
+                level: self.level,
+            }
+        }
+    }
+
+    /// A type with fields named like the bindings used while building it
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Job {
+        pub builder: String,
+        pub value: Option<u32>,
+    }
+
+    impl Job {
+        pub fn builder() -> JobBuilder {
+            JobBuilder::default()
+        }
+    }
+
+    #[derive(Default)]
+    pub struct JobBuilder {
+        builder: String,
+        value: Option<u32>,
+    }
+
+    impl JobBuilder {
+        pub fn builder(mut self, builder: String) -> Self {
+            self.builder = builder;
+            self
+        }
+
+        pub fn value(mut self, value: u32) -> Self {
+            self.value = Some(value);
+            self
+        }
+
+        pub fn build(self) -> Job {
+            Job {
+                builder: self.builder,
+                value: self.value,
+            }
+        }
+    }
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(into(builder = sdk::Request::builder), ty = sdk::Request)]
+pub struct Request {
+    // Each field is set calling the setter with the same name
+    pub name: String,
+    pub age: u16,
+    // Or a custom setter can be provided, while optional fields are only set when present
+    #[mapper(setter = with_nickname, opt)]
+    pub nickname: Option<String>,
+}
+// impl From<Request> for sdk::Request {
+//     fn from(Request { name, age, nickname }: Request) -> Self {
+//         let __builder = sdk::Request::builder();
+//         let __builder = __builder.name(Into::into(name));
+//         let __builder = __builder.age(Into::into(age));
+//         let __builder = if let Some(__value) = nickname.map(Into::into) {
+//             __builder.with_nickname(__value)
+//         } else {
+//             __builder
+//         };
+//         __builder.build()
+//     }
+// }
+
+// On `try_into` derives, the build method must return a `Result`
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(
+    try_into(builder = sdk::Request::builder, build = try_build),
+    ty = sdk::Request,
+    add(field = retries, default(value = 5))
+)]
+pub struct PartialRequest {
+    #[mapper(opt)]
+    pub name: Option<String>,
+    #[mapper(opt)]
+    pub age: Option<u32>,
+}
+
+// Builders whose setters take `&mut self` must be provided by mutable reference
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(into(builder = &mut sdk::Config::builder()), ty = sdk::Config)]
+pub struct Config {
+    #[mapper(opt)]
+    pub level: Option<u8>,
+    pub verbose: bool,
+}
+
+// Fields can be named `builder` or `value` without clashing with the builder itself
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(into(builder = sdk::Job::builder), ty = sdk::Job)]
+pub struct Job {
+    pub builder: String,
+    #[mapper(opt)]
+    pub value: Option<u32>,
+}
+
+fn main() {
+    let request: sdk::Request = Request {
+        name: "John".to_string(),
+        age: 30,
+        nickname: None,
+    }
+    .into();
+    assert_eq!(request.name(), "John");
+    assert_eq!(request.age(), 30);
+    assert_eq!(request.nickname(), None);
+    assert_eq!(request.retries(), 3);
+
+    let request = sdk::Request::try_from(PartialRequest {
+        name: Some("John".to_string()),
+        age: Some(30),
+    })
+    .unwrap();
+    assert_eq!(request.retries(), 5);
+
+    assert!(
+        sdk::Request::try_from(PartialRequest {
+            name: Some("John".to_string()),
+            age: None,
+        })
+        .is_err()
+    );
+
+    let config: sdk::Config = Config {
+        level: Some(2),
+        verbose: true,
+    }
+    .into();
+    assert_eq!(config.level(), Some(2));
+    assert!(config.verbose());
+
+    let job: sdk::Job = Job {
+        builder: "cron".to_string(),
+        value: Some(3),
+    }
+    .into();
+    assert_eq!(
+        job,
+        sdk::Job {
+            builder: "cron".to_string(),
+            value: Some(3)
+        }
+    );
+}
@@ -0,0 +1,112 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use model_mapper::{Codec, Mapper, TryCodec};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Foo {
+    pub price: i64,
+    pub tags: String,
+    pub discount: Option<i64>,
+}
+
+// A codec can be a type implementing the `Codec` trait
+pub struct Cents;
+
+impl Codec<f64, i64> for Cents {
+    fn encode(value: f64) -> i64 {
+        (value * 100.0).round() as i64
+    }
+
+    fn decode(value: i64) -> f64 {
+        value as f64 / 100.0
+    }
+}
+
+impl TryCodec<f64, i64> for Cents {
+    type Error = anyhow::Error;
+
+    fn try_encode(value: f64) -> Result<i64, Self::Error> {
+        if value.is_finite() {
+            Ok(Self::encode(value))
+        } else {
+            anyhow::bail!("Non-finite value")
+        }
+    }
+
+    fn try_decode(value: i64) -> Result<f64, Self::Error> {
+        Ok(Self::decode(value))
+    }
+}
+
+// Or just a module providing `encode` and `decode` functions
+mod comma_separated {
+    pub fn encode(value: Vec<String>) -> String {
+        value.join(",")
+    }
+
+    pub fn decode(value: String) -> Vec<String> {
+        value.split(',').map(ToString::to_string).collect()
+    }
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(from, into, ty = Foo)]
+pub struct Bar {
+    // The right function is picked for each direction
+    #[mapper(codec = Cents)]
+    pub price: f64,
+    #[mapper(codec = comma_separated)]
+    pub tags: Vec<String>,
+    // And it can be nested on other hints
+    #[mapper(opt(codec = Cents))]
+    pub discount: Option<f64>,
+}
+
+// For fallible derives, `try_encode` and `try_decode` are used instead
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(try_from, try_into, ty = Foo)]
+pub struct TryBar {
+    #[mapper(codec = Cents)]
+    pub price: f64,
+    pub tags: String,
+    #[mapper(opt(codec = Cents))]
+    pub discount: Option<f64>,
+}
+// impl TryFrom<TryBar> for Foo {
+//     type Error = anyhow::Error;
+//
+//     fn try_from(from: TryBar) -> Result<Self, Self::Error> {
+//         let TryBar { price, tags, discount } = from;
+//         Ok(Self {
+//             price: Cents::try_encode(price)?,
+//             tags: TryInto::try_into(tags)?,
+//             discount: MapInner::try_map_inner(discount, |discount| Cents::try_encode(discount))?,
+//         })
+//     }
+// }
+
+fn main() {
+    let foo = Foo {
+        price: 1250,
+        tags: "a,b".into(),
+        discount: Some(50),
+    };
+
+    let bar = Bar::from(foo.clone());
+    assert_eq!(bar.price, 12.5);
+    assert_eq!(bar.tags, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(bar.discount, Some(0.5));
+
+    let back: Foo = bar.into();
+    assert_eq!(back, foo);
+
+    let try_bar = TryBar::try_from(foo).unwrap();
+    assert_eq!(try_bar.price, 12.5);
+
+    let invalid = TryBar {
+        price: f64::NAN,
+        tags: String::new(),
+        discount: None,
+    };
+    assert!(Foo::try_from(invalid).is_err());
+}
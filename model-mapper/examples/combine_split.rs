@@ -0,0 +1,143 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Foo {
+    pub first_name: String,
+    pub last_name: String,
+    pub lat: f64,
+    pub lng: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Point {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, into, ty = Foo)]
+pub struct Bar {
+    // Many fields of the other type can be combined into a single one, and split back
+    #[mapper(
+        combine(fields = [first_name, last_name], with = join_name),
+        split(into = [first_name, last_name], with = split_name)
+    )]
+    pub full_name: String,
+    // Closures can be used as well
+    #[mapper(
+        combine(fields = [lat, lng], with = |lat, lng| Point { lat, lng }),
+        split(into = [lat, lng], with = |p: Point| (p.lat, p.lng))
+    )]
+    pub location: Point,
+}
+// impl From<Foo> for Bar {
+//     fn from(Foo { first_name: __full_name_first_name, last_name: __full_name_last_name, .. }: Foo) -> Self {
+//         Self {
+//             full_name: (join_name)(__full_name_first_name, __full_name_last_name),
+//             ..
+//         }
+//     }
+// }
+//
+// impl From<Bar> for Foo {
+//     fn from(Bar { full_name, location }: Bar) -> Self {
+//         let (__full_name_first_name, __full_name_last_name) = (split_name)(full_name);
+//         ..
+//         Self {
+//             first_name: __full_name_first_name,
+//             last_name: __full_name_last_name,
+//             ..
+//         }
+//     }
+// }
+
+fn join_name(first_name: String, last_name: String) -> String {
+    format!("{first_name} {last_name}")
+}
+
+fn split_name(full_name: String) -> (String, String) {
+    let (first_name, last_name) = full_name.split_once(' ').unwrap_or((&full_name, ""));
+    (first_name.to_string(), last_name.to_string())
+}
+
+// The fields of the other type are bound to reserved names, so they never clash with other fields of self
+#[derive(Debug, PartialEq, Clone)]
+pub struct Waypoint {
+    pub lat: f64,
+    pub lng: f64,
+    pub location_lat: f64,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, into, ty = Waypoint)]
+pub struct WaypointDto {
+    #[mapper(
+        combine(fields = [lat, lng], with = |lat, lng| Point { lat, lng }),
+        split(into = [lat, lng], with = |p: Point| (p.lat, p.lng))
+    )]
+    pub location: Point,
+    pub location_lat: f64,
+}
+
+// On fallible derives, the functions must return a `Result`
+#[derive(Debug, PartialEq, Clone)]
+pub struct Money {
+    pub amount: i64,
+    pub currency: String,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(try_from, ty = Money)]
+pub struct Price {
+    #[mapper(combine(fields = [amount, currency], with = format_price))]
+    pub label: String,
+}
+
+fn format_price(amount: i64, currency: String) -> anyhow::Result<String> {
+    if currency.len() != 3 {
+        anyhow::bail!("Invalid currency: {currency}")
+    }
+    Ok(format!("{amount} {currency}"))
+}
+
+fn main() {
+    let foo = Foo {
+        first_name: "John".to_string(),
+        last_name: "Doe".to_string(),
+        lat: 40.4,
+        lng: -3.7,
+    };
+
+    let bar = Bar::from(foo.clone());
+    assert_eq!(bar.full_name, "John Doe");
+    assert_eq!(bar.location, Point { lat: 40.4, lng: -3.7 });
+
+    let back: Foo = bar.into();
+    assert_eq!(back, foo);
+
+    let waypoint = Waypoint {
+        lat: 1.0,
+        lng: 2.0,
+        location_lat: 9.0,
+    };
+    let dto = WaypointDto::from(waypoint.clone());
+    assert_eq!(dto.location, Point { lat: 1.0, lng: 2.0 });
+    assert_eq!(dto.location_lat, 9.0);
+    assert_eq!(Waypoint::from(dto), waypoint);
+
+    let price = Price::try_from(Money {
+        amount: 10,
+        currency: "EUR".to_string(),
+    })
+    .unwrap();
+    assert_eq!(price.label, "10 EUR");
+    assert!(
+        Price::try_from(Money {
+            amount: 10,
+            currency: "EURO".to_string(),
+        })
+        .is_err()
+    );
+}
@@ -0,0 +1,91 @@
+#![allow(dead_code, clippy::disallowed_names, clippy::duplicated_attributes)]
+
+use model_mapper::Mapper;
+
+// The api layer
+#[derive(Debug, PartialEq, Clone)]
+pub struct ApiUser<T> {
+    pub id: i64,
+    pub name: String,
+    pub tag: T,
+}
+
+// The domain layer maps from and into the api layer
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, into, ty = "ApiUser<T>")]
+pub struct User<T> {
+    pub id: i64,
+    pub name: String,
+    pub tag: T,
+}
+
+// The persistence layer maps from and into the domain layer
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(derive(from, into, ty = "User<T>"))]
+// And it can also map the api layer, composing both conversions through the domain layer
+#[mapper(derive(from, into, ty = "ApiUser<T>", via = "User<T>"))]
+pub struct UserRow<T> {
+    pub id: i64,
+    pub name: String,
+    pub tag: T,
+}
+// impl<T, TSrc> From<ApiUser<TSrc>> for UserRow<T>
+// where
+//     TSrc: Into<T>,
+//     User<T>: From<ApiUser<TSrc>>,
+//     UserRow<T>: From<User<T>>,
+// {
+//     fn from(from: ApiUser<TSrc>) -> Self {
+//         let via: User<T> = From::from(from);
+//         <Self as From<User<T>>>::from(via)
+//     }
+// }
+
+// Fallible derives are composed as well, using `TryFrom` on both steps
+#[derive(Debug, PartialEq, Clone)]
+pub struct RawScore {
+    pub value: i64,
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(try_from, into, ty = RawScore)]
+pub struct Score {
+    pub value: u8,
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(derive(from, into, ty = Score))]
+#[mapper(derive(try_from, into, ty = RawScore, via = Score))]
+pub struct Grade {
+    pub value: u8,
+}
+// impl TryFrom<RawScore> for Grade {
+//     type Error = anyhow::Error;
+//
+//     fn try_from(from: RawScore) -> Result<Self, Self::Error> {
+//         let via: Score = TryFrom::try_from(from).map_err(Into::<anyhow::Error>::into)?;
+//         <Self as TryFrom<Score>>::try_from(via).map_err(Into::into)
+//     }
+// }
+
+fn main() {
+    let api = ApiUser {
+        id: 1,
+        name: "John".to_string(),
+        tag: 7u32,
+    };
+
+    let row = UserRow::<u64>::from(api.clone());
+    assert_eq!(row.id, 1);
+    assert_eq!(row.tag, 7u64);
+
+    let back: ApiUser<u64> = row.into();
+    assert_eq!(back.name, api.name);
+
+    let grade = Grade::try_from(RawScore { value: 9 }).unwrap();
+    assert_eq!(grade.value, 9);
+    assert!(Grade::try_from(RawScore { value: 1000 }).is_err());
+
+    let raw: RawScore = grade.into();
+    assert_eq!(raw.value, 9);
+}
@@ -0,0 +1,91 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+mod domain {
+    /// A type whose invariants are enforced by its constructors, like the `#[non_exhaustive]` ones from other crates
+    #[derive(Debug, PartialEq, Clone)]
+    #[non_exhaustive]
+    pub struct Email {
+        user: String,
+        domain: String,
+        verified: bool,
+    }
+
+    impl Email {
+        pub fn new(user: String, domain: String, verified: bool) -> Self {
+            Self {
+                user: user.to_lowercase(),
+                domain: domain.to_lowercase(),
+                verified,
+            }
+        }
+
+        pub fn try_new(user: String, domain: String, verified: bool) -> Result<Self, std::fmt::Error> {
+            if user.is_empty() || domain.is_empty() {
+                return Err(std::fmt::Error);
+            }
+            Ok(Self::new(user, domain, verified))
+        }
+
+        pub fn address(&self) -> String {
+            format!("{}@{}", self.user, self.domain)
+        }
+    }
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(
+    into(constructor = domain::Email::new(user, domain, verified)),
+    ty = domain::Email,
+    add(field = verified, default)
+)]
+pub struct EmailInput {
+    // Fields are passed as arguments after applying the usual hints, in the given order
+    pub user: String,
+    #[mapper(rename = domain)]
+    pub host: String,
+}
+// impl From<EmailInput> for domain::Email {
+//     fn from(EmailInput { user, host: domain }: EmailInput) -> Self {
+//         domain::Email::new(Into::into(user), Into::into(domain), Default::default())
+//     }
+// }
+
+// On `try_into` derives, the constructor must return a `Result`
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(
+    try_into(constructor = domain::Email::try_new(user, domain, verified)),
+    ty = domain::Email
+)]
+pub struct VerifiedEmailInput {
+    pub user: String,
+    pub domain: String,
+    pub verified: bool,
+}
+
+fn main() {
+    let email: domain::Email = EmailInput {
+        user: "John".to_string(),
+        host: "Doe.com".to_string(),
+    }
+    .into();
+    assert_eq!(email.address(), "john@doe.com");
+
+    let email = domain::Email::try_from(VerifiedEmailInput {
+        user: "john".to_string(),
+        domain: "doe.com".to_string(),
+        verified: true,
+    })
+    .unwrap();
+    assert_eq!(email.address(), "john@doe.com");
+
+    assert!(
+        domain::Email::try_from(VerifiedEmailInput {
+            user: String::new(),
+            domain: "doe.com".to_string(),
+            verified: true,
+        })
+        .is_err()
+    );
+}
@@ -0,0 +1,100 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use std::collections::{BTreeMap, VecDeque};
+
+use model_mapper::{MapInner, Mapper};
+
+/// Any container can be mapped by implementing the [MapInner] trait
+#[derive(Debug, PartialEq)]
+pub struct NonEmpty<T> {
+    pub head: T,
+    pub tail: Vec<T>,
+}
+
+impl<T> MapInner for NonEmpty<T> {
+    type Inner = T;
+    type Output<U> = NonEmpty<U>;
+
+    fn map_inner<U, F: FnMut(T) -> U>(self, mut f: F) -> NonEmpty<U> {
+        NonEmpty {
+            head: f(self.head),
+            tail: self.tail.into_iter().map(f).collect(),
+        }
+    }
+
+    fn try_map_inner<U, E, F: FnMut(T) -> Result<U, E>>(self, mut f: F) -> Result<NonEmpty<U>, E> {
+        Ok(NonEmpty {
+            head: f(self.head)?,
+            tail: self.tail.into_iter().map(f).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+pub struct Foo {
+    pub field1: NonEmpty<i64>,
+    pub field2: Option<NonEmpty<i64>>,
+    pub field3: VecDeque<i64>,
+    pub field4: BTreeMap<String, Box<i64>>,
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(try_from, into, ty = Foo)]
+pub struct Bar {
+    /// The `inner` hint maps the inner values of any [MapInner] container
+    #[mapper(inner)]
+    pub field1: NonEmpty<i32>,
+    /// And it can be nested like any other hint
+    #[mapper(opt(inner))]
+    pub field2: Option<NonEmpty<i32>>,
+    /// Standard containers implement it as well, preserving the container type
+    #[mapper(inner)]
+    pub field3: VecDeque<i32>,
+    #[mapper(inner(boxed))]
+    pub field4: BTreeMap<String, Box<i32>>,
+}
+// impl TryFrom<Foo> for Bar {
+//     type Error = anyhow::Error;
+//
+//     fn try_from(from: Foo) -> Result<Self, Self::Error> {
+//         let Foo { field1, field2, field3, field4 } = from;
+//         Ok(Self {
+//             field1: MapInner::try_map_inner(field1, |field1| TryInto::try_into(field1))?,
+//             field2: MapInner::try_map_inner(field2, |field2| {
+//                 MapInner::try_map_inner(field2, |field2| TryInto::try_into(field2))
+//             })?,
+//             ..
+//         })
+//     }
+// }
+
+fn main() {
+    let foo = Foo {
+        field1: NonEmpty {
+            head: 1,
+            tail: vec![2, 3],
+        },
+        field2: None,
+        field3: VecDeque::from([4]),
+        field4: BTreeMap::from([("a".into(), Box::new(5))]),
+    };
+
+    let bar = Bar::try_from(foo).unwrap();
+    assert_eq!(
+        bar.field1,
+        NonEmpty {
+            head: 1,
+            tail: vec![2, 3]
+        }
+    );
+
+    let foo = Foo {
+        field1: NonEmpty {
+            head: i64::MAX,
+            tail: vec![],
+        },
+        field2: None,
+        field3: VecDeque::new(),
+        field4: BTreeMap::new(),
+    };
+    assert!(Bar::try_from(foo).is_err());
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Entity {
+    pub id: i64,
+    pub name: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub version: u32,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, into, ty = Entity, fill_default)]
+pub struct Dto {
+    pub id: i64,
+    pub name: String,
+    // Skipped fields are populated from the default value of self, without requiring a custom function
+    #[mapper(skip)]
+    pub etag: Option<String>,
+    #[mapper(skip)]
+    pub links: Vec<String>,
+}
+// Self must implement `Default` when there are skipped fields, as well as the other type
+impl Default for Dto {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            name: String::new(),
+            etag: None,
+            links: vec!["self".to_string()],
+        }
+    }
+}
+// impl From<Entity> for Dto {
+//     fn from(Entity { id, name, .. }: Entity) -> Self {
+//         Self {
+//             id: Into::into(id),
+//             name: Into::into(name),
+//             ..Default::default()
+//         }
+//     }
+// }
+//
+// impl From<Dto> for Entity {
+//     fn from(Dto { id, name, etag, links }: Dto) -> Self {
+//         Self {
+//             id: Into::into(id),
+//             name: Into::into(name),
+//             ..Default::default()
+//         }
+//     }
+// }
+
+fn main() {
+    let entity = Entity {
+        id: 1,
+        name: "John".to_string(),
+        created_at: 100,
+        updated_at: 200,
+        version: 3,
+    };
+
+    let dto = Dto::from(entity);
+    assert_eq!(dto.id, 1);
+    assert_eq!(dto.etag, None);
+    assert_eq!(dto.links, vec!["self".to_string()]);
+
+    let entity: Entity = dto.into();
+    assert_eq!(entity.name, "John");
+    assert_eq!(entity.created_at, 0);
+    assert_eq!(entity.version, 0);
+}
@@ -0,0 +1,132 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub zip: u32,
+}
+
+// The dto flattens the address into the parent fields
+#[derive(Debug, PartialEq, Clone)]
+pub struct UserDto {
+    pub name: String,
+    pub street: String,
+    pub city: String,
+    pub postal_code: u64,
+    pub billing_street: Option<String>,
+    pub billing_city: Option<String>,
+    pub billing_zip: Option<u64>,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(into, try_from, ty = UserDto)]
+pub struct User {
+    pub name: String,
+    // While the domain keeps the nested struct, the fields can also be renamed
+    #[mapper(flatten(street, city, zip = postal_code))]
+    pub address: Address,
+    // Optional structs are flattened into optional fields, it will be present only if every field is
+    #[mapper(flatten(street = billing_street, city = billing_city, zip = billing_zip, opt))]
+    pub billing: Option<Address>,
+}
+// impl From<User> for UserDto {
+//     fn from(User { name, address, billing }: User) -> Self {
+//         let Address { street: __address_street, city: __address_city, zip: __address_zip } = address;
+//         let (__address_street, __address_city, __address_zip) =
+//             (Into::into(__address_street), Into::into(__address_city), Into::into(__address_zip));
+//         let (__billing_street, __billing_city, __billing_zip) = match billing {
+//             Some(Address { street: __billing_street, city: __billing_city, zip: __billing_zip }) => (
+//                 Some(Into::into(__billing_street)),
+//                 Some(Into::into(__billing_city)),
+//                 Some(Into::into(__billing_zip)),
+//             ),
+//             None => (None, None, None),
+//         };
+//         Self {
+//             street: __address_street,
+//             city: __address_city,
+//             postal_code: __address_zip,
+//             billing_street: __billing_street,
+//             billing_city: __billing_city,
+//             billing_zip: __billing_zip,
+//             name: Into::into(name),
+//         }
+//     }
+// }
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Coords {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MarkerDto {
+    pub x: i32,
+    pub y: i32,
+    pub position_x: i32,
+}
+
+// The flattened fields never clash with the other fields of self, whatever their names
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, into, ty = MarkerDto)]
+pub struct Marker {
+    #[mapper(flatten(x, y))]
+    pub position: Coords,
+    pub position_x: i32,
+}
+
+fn main() {
+    let user = User {
+        name: "John".to_string(),
+        address: Address {
+            street: "Main St".to_string(),
+            city: "Springfield".to_string(),
+            zip: 12345,
+        },
+        billing: None,
+    };
+
+    let dto: UserDto = user.clone().into();
+    assert_eq!(dto.street, "Main St");
+    assert_eq!(dto.postal_code, 12345);
+    assert_eq!(dto.billing_city, None);
+
+    let back = User::try_from(dto.clone()).unwrap();
+    assert_eq!(back, user);
+
+    let dto = UserDto {
+        billing_street: Some("Side St".to_string()),
+        billing_city: Some("Shelbyville".to_string()),
+        billing_zip: Some(54321),
+        ..dto
+    };
+    let user = User::try_from(dto.clone()).unwrap();
+    assert_eq!(user.billing.unwrap().city, "Shelbyville");
+
+    // On fallible derives, having just some of the optional fields fails
+    let partial = UserDto {
+        billing_zip: None,
+        ..dto.clone()
+    };
+    assert!(User::try_from(partial).is_err());
+
+    let invalid = UserDto {
+        postal_code: u64::MAX,
+        ..dto
+    };
+    assert!(User::try_from(invalid).is_err());
+
+    let dto = MarkerDto {
+        x: 1,
+        y: 2,
+        position_x: 3,
+    };
+    let marker = Marker::from(dto.clone());
+    assert_eq!(marker.position, Coords { x: 1, y: 2 });
+    assert_eq!(marker.position_x, 3);
+    assert_eq!(MarkerDto::from(marker), dto);
+}
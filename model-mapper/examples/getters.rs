@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+mod sdk {
+    /// A type with private fields, like the ones generated by protobuf or provided by SDK clients
+    #[derive(Debug, Clone)]
+    pub struct Account {
+        id: u32,
+        name: String,
+        email: Option<String>,
+        tags: Vec<String>,
+        balance: i64,
+    }
+
+    impl Account {
+        pub fn new(id: u32, name: &str, email: Option<&str>, tags: &[&str], balance: i64) -> Self {
+            Self {
+                id,
+                name: name.to_string(),
+                email: email.map(str::to_string),
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                balance,
+            }
+        }
+
+        pub fn id(&self) -> u32 {
+            self.id
+        }
+
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        pub fn email(&self) -> Option<&str> {
+            self.email.as_deref()
+        }
+
+        pub fn get_balance(&self) -> i64 {
+            self.balance
+        }
+
+        pub fn into_tags(self) -> Vec<String> {
+            self.tags
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Range {
+        from: u32,
+        to: u32,
+    }
+
+    impl Range {
+        pub fn new(from: u32, to: u32) -> Self {
+            Self { from, to }
+        }
+
+        pub fn from(&self) -> u32 {
+            self.from
+        }
+
+        pub fn to(&self) -> u32 {
+            self.to
+        }
+    }
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from(getters), ty = sdk::Account)]
+pub struct Account {
+    // Fields are read through the getter with the same name
+    pub id: u64,
+    // Getter results flow through the usual hints
+    #[mapper(opt)]
+    pub email: Option<String>,
+    // Renamed fields use the getter of the renamed one
+    #[mapper(rename = name)]
+    pub display_name: String,
+    // Or a custom getter can be provided
+    #[mapper(getter = get_balance())]
+    pub balance: i64,
+    // Getters taking the other type by value (named `into_*`) are called last
+    #[mapper(getter = into_tags())]
+    pub tags: Vec<String>,
+}
+// impl From<sdk::Account> for Account {
+//     fn from(from: sdk::Account) -> Self {
+//         let __from = from;
+//         let id = __from.id();
+//         let id = Into::into(id);
+//         let email = __from.email();
+//         let email = email.map(Into::into);
+//         ..
+//         let tags = __from.into_tags();
+//         let tags = Into::into(tags);
+//         Self { id, email, display_name, balance, tags }
+//     }
+// }
+
+// Fields named `from` don't clash with the value being converted
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from(getters), ty = sdk::Range)]
+pub struct Range {
+    pub from: u32,
+    pub to: u32,
+}
+
+fn main() {
+    let account = sdk::Account::new(1, "John", Some("john@doe.com"), &["admin"], 100);
+
+    let mapped = Account::from(account);
+    assert_eq!(mapped.id, 1);
+    assert_eq!(mapped.email.as_deref(), Some("john@doe.com"));
+    assert_eq!(mapped.display_name, "John");
+    assert_eq!(mapped.balance, 100);
+    assert_eq!(mapped.tags, vec!["admin".to_string()]);
+
+    let range = Range::from(sdk::Range::new(1, 5));
+    assert_eq!(range, Range { from: 1, to: 5 });
+}
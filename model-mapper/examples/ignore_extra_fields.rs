@@ -121,4 +121,42 @@ mod ignore_extra_explicit {
     }
 }
 
+mod ignore_list {
+    use super::*;
+
+    struct Foo {
+        field1: String,
+        field2: i64,
+        field3: Option<i32>,
+        field4: Option<String>,
+    }
+
+    /// Extra fields can be explicitly listed instead, so any other new field of [Foo] still fails to compile.
+    /// When deriving 'into' they're populated with their default value, so the whole [Foo] doesn't need to implement
+    /// [Default]
+    #[derive(Mapper)]
+    #[mapper(into, from, ty = Foo, ignore(field3, field4))]
+    struct Bar {
+        field1: String,
+        field2: i64,
+    }
+
+    enum FooEnum {
+        One,
+        Two { value: i32, extra: bool },
+        Three,
+        Four,
+    }
+
+    /// The same applies to variants and their fields
+    #[derive(Mapper, Default)]
+    #[mapper(from, ty = FooEnum, ignore(Three, Four))]
+    enum BarEnum {
+        #[default]
+        One,
+        #[mapper(ignore(extra))]
+        Two { value: i32 },
+    }
+}
+
 fn main() {}
@@ -0,0 +1,74 @@
+use model_mapper::{Mapper, ParseEnumError};
+
+// C-like enums can be mapped to and from integers, using their discriminants
+#[derive(Debug, PartialEq, Eq, Mapper)]
+#[mapper(derive(int))]
+enum Priority {
+    Low,
+    Medium,
+    High = 10,
+    Critical,
+}
+// impl From<Priority> for i32 {
+//     fn from(from: Priority) -> Self {
+//         match from {
+//             Priority::Low => 0,
+//             Priority::Medium => (0) + 1,
+//             Priority::High => 10,
+//             Priority::Critical => (10) + 1,
+//         }
+//     }
+// }
+// impl TryFrom<i32> for Priority {
+//     type Error = ParseEnumError;
+//     fn try_from(value: i32) -> Result<Self, Self::Error> {
+//         Ok(match value {
+//             value if value == (0) => Self::Low,
+//             value if value == ((0) + 1) => Self::Medium,
+//             value if value == (10) => Self::High,
+//             value if value == ((10) + 1) => Self::Critical,
+//             _ => return Err(ParseEnumError::new("Priority")),
+//         })
+//     }
+// }
+
+// The integer type can be customized, as well as the code of every variant
+#[derive(Debug, PartialEq, Eq, Mapper)]
+#[mapper(derive(int, ty = u8, fallback = Other))]
+enum Opcode {
+    #[mapper(code = 0x01)]
+    Read,
+    #[mapper(code = 0x02)]
+    Write,
+    // Any unknown value is mapped into the fallback instead of failing, so the conversion is infallible
+    #[mapper(code = 0xFF)]
+    Other,
+}
+
+// The fallback can also keep the unknown value
+#[derive(Debug, PartialEq, Eq, Mapper)]
+#[repr(i64)]
+#[mapper(derive(int, ty = i64, fallback = Unknown))]
+enum Status {
+    Active = 1,
+    Inactive = 2,
+    Unknown(i64),
+}
+
+fn main() {
+    assert_eq!(i32::from(Priority::Low), 0);
+    assert_eq!(i32::from(Priority::Medium), 1);
+    assert_eq!(i32::from(Priority::Critical), 11);
+    assert_eq!(Priority::try_from(10), Ok(Priority::High));
+    assert_eq!(Priority::try_from(2), Err(ParseEnumError::new("Priority")));
+
+    assert_eq!(u8::from(Opcode::Write), 2);
+    assert_eq!(u8::from(Opcode::Other), 255);
+    assert_eq!(Opcode::from(1), Opcode::Read);
+    assert_eq!(Opcode::from(7), Opcode::Other);
+
+    assert_eq!(i64::from(Status::Inactive), 2);
+    assert_eq!(i64::from(Status::Unknown(5)), 5);
+    assert_eq!(Status::from(1), Status::Active);
+    assert_eq!(Status::from(5), Status::Unknown(5));
+}
@@ -0,0 +1,90 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum JobStatus {
+    Pending,
+    Queued,
+    Running { progress: u8 },
+    Done,
+}
+
+// Many variants of the other enum can be mapped into a single one, choosing which one it's mapped back into
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = JobStatus)]
+enum Status {
+    #[mapper(from_variants = [Pending, Queued], into_variant = Queued)]
+    Waiting,
+    #[mapper(from_variants = [Running, Done], into_variant = Done, ignore_extra)]
+    Active,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Shape {
+    Polygon { sides: u32 },
+    Circle { radius: u32 },
+}
+
+// And a variant of the other enum can be split into many ones, told apart by a predicate on its fields
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(try_from, into, ty = Shape)]
+enum Figure {
+    #[mapper(rename = Polygon, split_by = sides == 3)]
+    Triangle {
+        sides: u32,
+    },
+    #[mapper(rename = Polygon, split_by = sides == 4)]
+    Square {
+        sides: u32,
+    },
+    Circle {
+        radius: u32,
+    },
+}
+
+// The last variant without a predicate takes any value not matching the previous ones, so it can't fail
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, ty = Shape)]
+enum Outline {
+    #[mapper(rename = Polygon, split_by = sides < 3)]
+    Degenerate {
+        sides: u32,
+    },
+    #[mapper(rename = Polygon)]
+    Polygon {
+        sides: u32,
+    },
+    Circle {
+        radius: u32,
+    },
+}
+
+fn main() {
+    assert_eq!(Status::from(JobStatus::Pending), Status::Waiting);
+    assert_eq!(Status::from(JobStatus::Queued), Status::Waiting);
+    assert_eq!(Status::from(JobStatus::Running { progress: 50 }), Status::Active);
+    assert_eq!(Status::from(JobStatus::Done), Status::Active);
+    assert_eq!(JobStatus::from(Status::Waiting), JobStatus::Queued);
+    assert_eq!(JobStatus::from(Status::Active), JobStatus::Done);
+
+    assert_eq!(
+        Figure::try_from(Shape::Polygon { sides: 3 }).unwrap(),
+        Figure::Triangle { sides: 3 }
+    );
+    assert_eq!(
+        Figure::try_from(Shape::Polygon { sides: 4 }).unwrap(),
+        Figure::Square { sides: 4 }
+    );
+    assert!(Figure::try_from(Shape::Polygon { sides: 5 }).is_err());
+    assert_eq!(Shape::from(Figure::Square { sides: 4 }), Shape::Polygon { sides: 4 });
+
+    assert_eq!(
+        Outline::from(Shape::Polygon { sides: 2 }),
+        Outline::Degenerate { sides: 2 }
+    );
+    assert_eq!(
+        Outline::from(Shape::Polygon { sides: 5 }),
+        Outline::Polygon { sides: 5 }
+    );
+}
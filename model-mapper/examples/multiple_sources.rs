@@ -0,0 +1,101 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct User {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Profile {
+    pub bio: String,
+    pub avatar: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Settings {
+    pub id: i64,
+    pub theme: String,
+    pub notifications: bool,
+}
+
+// Every part is deconstructed, so fields of a part not mapped must be ignored, qualified with their type unless they
+// belong to the first one
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, ty = (User, Profile, Settings), ignore(Settings::id))]
+pub struct UserDto {
+    // Fields are taken from the first type by default
+    pub id: i64,
+    pub name: String,
+    // Or from any other type if the source is provided
+    #[mapper(source = Profile)]
+    pub bio: String,
+    #[mapper(source = Profile, rename = avatar)]
+    pub avatar_url: Option<String>,
+    #[mapper(source = Settings)]
+    pub theme: String,
+    #[mapper(source = Settings)]
+    pub notifications: bool,
+}
+// impl UserDto {
+//     pub fn from_parts(user: User, profile: Profile, settings: Settings) -> Self {
+//         let (User { id, name }, Profile { bio, avatar: avatar_url }, Settings { theme, notifications, id: _ }) =
+//             (user, profile, settings);
+//         Self {
+//             id: Into::into(id),
+//             ..
+//         }
+//     }
+// }
+//
+// impl From<(User, Profile, Settings)> for UserDto {
+//     fn from((user, profile, settings): (User, Profile, Settings)) -> Self {
+//         Self::from_parts(user, profile, settings)
+//     }
+// }
+
+// Or every extra field of any part can be ignored at once
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(try_from, ty = (User, Profile), ignore_extra)]
+pub struct UserSummary {
+    pub name: String,
+    #[mapper(source = Profile, from_path = avatar?)]
+    pub avatar: String,
+}
+
+fn main() {
+    let user = User {
+        id: 1,
+        name: "John".to_string(),
+    };
+    let profile = Profile {
+        bio: "Hello!".to_string(),
+        avatar: Some("avatar.png".to_string()),
+    };
+    let settings = Settings {
+        id: 2,
+        theme: "dark".to_string(),
+        notifications: true,
+    };
+
+    let dto = UserDto::from((user.clone(), profile.clone(), settings.clone()));
+    assert_eq!(dto.id, 1);
+    assert_eq!(dto.bio, "Hello!");
+    assert_eq!(dto.avatar_url.as_deref(), Some("avatar.png"));
+    assert_eq!(dto.theme, "dark");
+
+    let other = UserDto::from_parts(user.clone(), profile.clone(), settings);
+    assert_eq!(other, dto);
+
+    let summary = UserSummary::try_from_parts(user.clone(), profile).unwrap();
+    assert_eq!(summary.name, "John");
+    assert_eq!(summary.avatar, "avatar.png");
+
+    let no_avatar = Profile {
+        bio: String::new(),
+        avatar: None,
+    };
+    assert!(UserSummary::try_from((user, no_avatar)).is_err());
+}
@@ -0,0 +1,106 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct NewUser {
+    pub email: String,
+    pub password: String,
+    pub active: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct NewProfile {
+    pub email: String,
+    pub display_name: String,
+    pub bio: Option<String>,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(
+    into,
+    ty = (NewUser, NewProfile),
+    add(field = active, default(value = true)),
+    add(field = bio, target = NewProfile, default)
+)]
+pub struct CreateUserRequest {
+    // Fields are mapped into the first type by default, or cloned into many of them if provided
+    #[mapper(target = [NewUser, NewProfile])]
+    pub email: String,
+    pub password: String,
+    // Or into any other type
+    #[mapper(target = NewProfile, rename = display_name)]
+    pub name: String,
+}
+// impl CreateUserRequest {
+//     pub fn into_parts(self) -> (NewUser, NewProfile) {
+//         let CreateUserRequest { email, password, name: display_name } = self;
+//         let __email_new_user = Clone::clone(&email);
+//         (
+//             NewUser { email: Into::into(__email_new_user), password: Into::into(password), active: true },
+//             NewProfile { email: Into::into(email), display_name: Into::into(display_name), bio: Default::default() },
+//         )
+//     }
+// }
+//
+// impl From<CreateUserRequest> for (NewUser, NewProfile) {
+//     fn from(from: CreateUserRequest) -> Self {
+//         from.into_parts()
+//     }
+// }
+
+// Additional fields without a default value are provided on custom functions
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(
+    try_into(custom = split),
+    ty = (NewUser, NewProfile),
+    add(field = active, ty = bool),
+    add(field = bio, target = NewProfile, ty = "Option<String>")
+)]
+pub struct SignUpForm {
+    #[mapper(target = [NewUser, NewProfile])]
+    pub email: String,
+    #[mapper(with = hash_password)]
+    pub password: String,
+    #[mapper(target = NewProfile)]
+    pub display_name: String,
+}
+
+fn hash_password(password: String) -> anyhow::Result<String> {
+    if password.len() < 8 {
+        anyhow::bail!("Password too short")
+    }
+    Ok(password.chars().rev().collect())
+}
+
+fn main() {
+    let request = CreateUserRequest {
+        email: "john@doe.com".to_string(),
+        password: "secret".to_string(),
+        name: "John".to_string(),
+    };
+
+    let (user, profile): (NewUser, NewProfile) = request.clone().into();
+    assert_eq!(user.email, "john@doe.com");
+    assert!(user.active);
+    assert_eq!(profile.email, "john@doe.com");
+    assert_eq!(profile.display_name, "John");
+    assert_eq!(profile.bio, None);
+    assert_eq!(request.into_parts(), (user, profile));
+
+    let form = SignUpForm {
+        email: "john@doe.com".to_string(),
+        password: "password".to_string(),
+        display_name: "John".to_string(),
+    };
+    let (user, profile) = form.clone().split(false, Some("Hi!".to_string())).unwrap();
+    assert_eq!(user.password, "drowssap");
+    assert!(!user.active);
+    assert_eq!(profile.bio.as_deref(), Some("Hi!"));
+
+    let short = SignUpForm {
+        password: "short".to_string(),
+        ..form
+    };
+    assert!(short.split(true, None).is_err());
+}
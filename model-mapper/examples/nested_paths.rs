@@ -0,0 +1,119 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Contact {
+    pub email: String,
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Profile {
+    pub contact: Contact,
+    pub company: Option<Company>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Company {
+    pub name: String,
+    pub employees: u32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Foo {
+    pub id: i64,
+    pub profile: Profile,
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(from, ty = Foo)]
+pub struct Bar {
+    pub id: i64,
+    // The value is pulled from deep inside the other type
+    #[mapper(from_path = profile.contact.email)]
+    pub email: String,
+    // Many fields can be pulled from the same field
+    #[mapper(from_path = profile.contact.phone)]
+    pub phone: Option<String>,
+}
+// impl From<Foo> for Bar {
+//     fn from(Foo { id, profile }: Foo) -> Self {
+//         Self {
+//             id: Into::into(id),
+//             email: {
+//                 let email = profile.contact.email;
+//                 Into::into(email)
+//             },
+//             phone: {
+//                 let phone = profile.contact.phone;
+//                 Into::into(phone)
+//             },
+//         }
+//     }
+// }
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(try_from, ty = Foo)]
+pub struct Baz {
+    pub id: i64,
+    // On fallible derives, optional values can be chained, failing if missing
+    #[mapper(from_path = profile.company?.employees)]
+    pub employees: u16,
+    // The optional values are unwrapped once, so many fields can be pulled from them
+    #[mapper(from_path = profile.company?.name)]
+    pub company: String,
+    #[mapper(from_path = "profile.contact.phone?")]
+    pub phone: String,
+}
+// impl TryFrom<Foo> for Baz {
+//     type Error = anyhow::Error;
+//
+//     fn try_from(Foo { id, profile }: Foo) -> Result<Self, Self::Error> {
+//         let __profile_company = profile
+//             .company
+//             .ok_or_else(|| anyhow::anyhow!("Missing value for 'profile.company'"))?;
+//         Ok(Self {
+//             id: TryInto::try_into(id)?,
+//             employees: {
+//                 let employees = __profile_company.employees;
+//                 TryInto::try_into(employees)?
+//             },
+//             company: {
+//                 let company = __profile_company.name;
+//                 TryInto::try_into(company)?
+//             },
+//             ..
+//         })
+//     }
+// }
+
+fn main() {
+    let foo = Foo {
+        id: 1,
+        profile: Profile {
+            contact: Contact {
+                email: "john@example.com".to_string(),
+                phone: Some("555-1234".to_string()),
+            },
+            company: Some(Company {
+                name: "ACME".to_string(),
+                employees: 42,
+            }),
+        },
+    };
+
+    let bar = Bar::from(foo.clone());
+    assert_eq!(bar.email, "john@example.com");
+    assert_eq!(bar.phone.as_deref(), Some("555-1234"));
+
+    let baz = Baz::try_from(foo.clone()).unwrap();
+    assert_eq!(baz.employees, 42);
+    assert_eq!(baz.company, "ACME");
+    assert_eq!(baz.phone, "555-1234");
+
+    let mut missing = foo.clone();
+    missing.profile.company = None;
+    let err = Baz::try_from(missing).unwrap_err();
+    assert_eq!(err.to_string(), "Missing value for 'profile.company'");
+}
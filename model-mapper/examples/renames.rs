@@ -40,7 +40,72 @@ enum BarEnum {
     },
 }
 
+// Types generated from other ecosystems usually follow different conventions
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct UserDto {
+    userName: String,
+    r#type: String,
+    isActive: bool,
+    externalId: i64,
+}
+
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProtoStatus {
+    STATUS_ACTIVE,
+    STATUS_INACTIVE,
+    STATUS_BANNED,
+}
+
+// So the names on the other type can be computed with rename rules, instead of renaming every field
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = UserDto, rename_all = "camelCase")]
+struct User {
+    user_name: String,
+    // Keywords are mapped into raw identifiers
+    r#type: String,
+    // Explicit renames still take precedence
+    #[mapper(rename = isActive)]
+    active: bool,
+    external_id: i64,
+}
+
+// Prefixes and suffixes can be added after converting the names
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(derive(from, into, ty = ProtoStatus, rename_all = "SCREAMING_SNAKE_CASE", add_prefix = "STATUS_"))]
+enum Status {
+    Active,
+    Inactive,
+    Banned,
+}
+
+// Or stripped before converting them
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = Status, strip_prefix = "UserStatus")]
+enum UserStatus {
+    UserStatusActive,
+    UserStatusInactive,
+    UserStatusBanned,
+}
+
 fn main() {
+    let dto = UserDto {
+        userName: "john".into(),
+        r#type: "admin".into(),
+        isActive: true,
+        externalId: 7,
+    };
+    let user = User::from(dto.clone());
+    assert_eq!(user.user_name, "john");
+    assert!(user.active);
+    assert_eq!(UserDto::from(user), dto);
+
+    assert_eq!(ProtoStatus::from(Status::Banned), ProtoStatus::STATUS_BANNED);
+    assert_eq!(Status::from(ProtoStatus::STATUS_ACTIVE), Status::Active);
+    assert_eq!(UserStatus::from(Status::Inactive), UserStatus::UserStatusInactive);
+
     let source = FooEnum::Three {
         field1: "val".into(),
         field2: true,
@@ -0,0 +1,63 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Shape {
+    Circle(f64),
+    Rectangle(f64, f64),
+    Square { side: f64 },
+    Triangle(f64, f64, bool),
+}
+
+// Named variants can be reshaped from and into tuple variants, mapping the fields in the order they're declared
+// unless an index is provided
+#[derive(Debug, Clone, PartialEq, Mapper)]
+#[mapper(from, into, ty = Shape)]
+enum Figure {
+    #[mapper(shape = tuple)]
+    Circle { radius: f64 },
+    #[mapper(shape = tuple)]
+    Rectangle {
+        #[mapper(index = 1)]
+        height: f64,
+        #[mapper(index = 0)]
+        width: f64,
+    },
+    // And tuple variants from and into named variants, given the name of every field
+    #[mapper(shape = named)]
+    Square(#[mapper(name = side)] f64),
+    // Hints are applied as usual and additional positional fields are supplied by index
+    #[mapper(shape = tuple, add(field = filled, index = 2, default))]
+    Triangle {
+        #[mapper(from_with = to_f32, into_with = f64::from)]
+        base: f32,
+        height: f64,
+    },
+}
+
+fn to_f32(value: f64) -> f32 {
+    value as f32
+}
+
+fn main() {
+    assert_eq!(Figure::from(Shape::Circle(1.0)), Figure::Circle { radius: 1.0 });
+    assert_eq!(Shape::from(Figure::Circle { radius: 1.0 }), Shape::Circle(1.0));
+
+    let rectangle = Figure::from(Shape::Rectangle(2.0, 3.0));
+    assert_eq!(
+        rectangle,
+        Figure::Rectangle {
+            width: 2.0,
+            height: 3.0
+        }
+    );
+    assert_eq!(Shape::from(rectangle), Shape::Rectangle(2.0, 3.0));
+
+    assert_eq!(Figure::from(Shape::Square { side: 4.0 }), Figure::Square(4.0));
+    assert_eq!(Shape::from(Figure::Square(4.0)), Shape::Square { side: 4.0 });
+
+    let triangle = Figure::from(Shape::Triangle(1.5, 2.0, true));
+    assert_eq!(triangle, Figure::Triangle { base: 1.5, height: 2.0 });
+    assert_eq!(Shape::from(triangle), Shape::Triangle(1.5, 2.0, false));
+}
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use model_mapper::{Mapper, ParseEnumError};
+
+// Unit-variant enums can be mapped to and from strings, using the variant names as they are
+#[derive(Debug, PartialEq, Eq, Mapper)]
+#[mapper(derive(str))]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+// impl Color {
+//     pub fn as_str(&self) -> &'static str {
+//         match self {
+//             Self::Red => "Red",
+//             Self::Green => "Green",
+//             Self::Blue => "Blue",
+//         }
+//     }
+// }
+// impl Display for Color { .. }
+// impl FromStr for Color { .. }
+// impl TryFrom<&str> for Color { .. }
+
+// Or converting them to some case convention, renaming and aliasing some of them
+#[derive(Debug, PartialEq, Eq, Mapper)]
+#[mapper(derive(str, case = "SCREAMING_SNAKE_CASE"))]
+enum Status {
+    Pending,
+    InProgress,
+    #[mapper(rename = done, alias = "COMPLETED", alias = "FINISHED")]
+    Completed,
+}
+
+// The strings can also be parsed regardless of their case
+#[derive(Debug, PartialEq, Eq, Mapper)]
+#[mapper(derive(str, case = "kebab-case", case_insensitive))]
+enum Method {
+    Get,
+    Post,
+    #[mapper(alias = "del")]
+    Delete,
+}
+
+// Along with any other derive
+#[derive(Debug, PartialEq, Eq, Mapper)]
+#[mapper(derive(str, case = "lowercase"))]
+#[mapper(derive(from, into, ty = Color))]
+enum OtherColor {
+    Red,
+    Green,
+    Blue,
+}
+
+fn main() {
+    assert_eq!(Color::Red.as_str(), "Red");
+    assert_eq!(Color::Green.to_string(), "Green");
+    assert_eq!(Color::from_str("Blue"), Ok(Color::Blue));
+    assert_eq!(Color::try_from("blue"), Err(ParseEnumError::new("Color")));
+
+    assert_eq!(Status::InProgress.as_str(), "IN_PROGRESS");
+    assert_eq!(Status::Completed.as_str(), "done");
+    assert_eq!("PENDING".parse(), Ok(Status::Pending));
+    assert_eq!("done".parse(), Ok(Status::Completed));
+    assert_eq!("FINISHED".parse(), Ok(Status::Completed));
+    assert!("pending".parse::<Status>().is_err());
+
+    assert_eq!(Method::Delete.to_string(), "delete");
+    assert_eq!("GET".parse(), Ok(Method::Get));
+    assert_eq!("Del".parse(), Ok(Method::Delete));
+
+    let color: Color = OtherColor::Blue.into();
+    assert_eq!(OtherColor::from(color).as_str(), "blue");
+}
@@ -0,0 +1,87 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Point(i64, i64);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Version(u32, u32, u32);
+
+// Named fields can be mapped from and into the positions of a tuple struct
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = Point)]
+struct Coordinates {
+    #[mapper(index = 1)]
+    y: i64,
+    #[mapper(index = 0)]
+    x: i64,
+}
+
+// And the other way around, unnamed fields can be mapped from and into named fields
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = Coordinates)]
+struct Position(#[mapper(name = x)] i64, #[mapper(name = y)] i64);
+
+// Positions can also be swapped between tuple structs
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = Point)]
+struct Swapped(#[mapper(index = 1)] i64, #[mapper(index = 0)] i64);
+
+// Plain tuples are mapped by position, in the order the fields are declared
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = "(String, i64)")]
+struct Entry {
+    key: String,
+    value: i64,
+}
+
+// Unless an index is given
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(derive(from, into, ty = "(u32, u32, u32)"), derive(from, into, ty = Version))]
+struct Release {
+    #[mapper(index = 2)]
+    patch: u32,
+    #[mapper(index = 0)]
+    major: u32,
+    #[mapper(index = 1)]
+    minor: u32,
+}
+
+// And the elements not mapped can be ignored
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, ty = "(u32, u32, u32)", ignore_extra)]
+struct Major {
+    major: u32,
+}
+
+fn main() {
+    let coordinates = Coordinates::from(Point(1, 2));
+    assert_eq!(coordinates, Coordinates { x: 1, y: 2 });
+    assert_eq!(Point::from(coordinates.clone()), Point(1, 2));
+
+    let position = Position::from(coordinates.clone());
+    assert_eq!(position, Position(1, 2));
+    assert_eq!(Coordinates::from(position), coordinates);
+
+    assert_eq!(Swapped::from(Point(1, 2)), Swapped(2, 1));
+    assert_eq!(Point::from(Swapped(2, 1)), Point(1, 2));
+
+    let entry = Entry::from(("answer".to_string(), 42));
+    assert_eq!(entry.key, "answer");
+    assert_eq!(<(String, i64)>::from(entry), ("answer".to_string(), 42));
+
+    let release = Release::from((1, 2, 3));
+    assert_eq!(
+        release,
+        Release {
+            major: 1,
+            minor: 2,
+            patch: 3
+        }
+    );
+    assert_eq!(<(u32, u32, u32)>::from(release.clone()), (1, 2, 3));
+    assert_eq!(Version::from(release), Version(1, 2, 3));
+
+    assert_eq!(Major::from((1, 2, 3)), Major { major: 1 });
+}
@@ -0,0 +1,101 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use std::time::Duration;
+
+use model_mapper::Mapper;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Foo {
+    pub timeout: u64,
+    pub retry_delay: Option<u64>,
+    pub backoff: Vec<u64>,
+    pub id: u64,
+    pub idle: u64,
+}
+
+fn duration_to_millis(value: Duration) -> u64 {
+    value.as_millis() as u64
+}
+
+fn millis_to_duration(value: u64) -> Duration {
+    Duration::from_millis(value)
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(
+    from,
+    into,
+    ty = Foo,
+    // Every field of type `Duration` will be converted with the given function when deriving 'into'
+    convert(into, src = Duration, dst = u64, with = duration_to_millis),
+    // And the other way around when deriving 'from', as the rules of derives with both directions must set one
+    convert(from, src = u64, dst = Duration, with = millis_to_duration)
+)]
+pub struct Bar {
+    pub timeout: Duration,
+    // It also applies to the inner types when using hints
+    #[mapper(opt)]
+    pub retry_delay: Option<Duration>,
+    #[mapper(iter)]
+    pub backoff: Vec<Duration>,
+    // Rules are matched against self types ('src' when deriving 'into' and 'dst' when deriving 'from'), so fields of
+    // any other type are left untouched
+    pub id: u64,
+    // And fields providing an explicit function take priority over the rules
+    #[mapper(from_with = Duration::from_secs, into_with = idle.as_secs())]
+    pub idle: Duration,
+}
+
+// Conversions are scoped to each derive
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(derive(
+    try_into,
+    ty = Foo,
+    convert(src = Duration, dst = u64, with = try_duration_to_millis)
+))]
+pub struct TryBar {
+    pub timeout: Duration,
+    #[mapper(opt)]
+    pub retry_delay: Option<Duration>,
+    #[mapper(iter)]
+    pub backoff: Vec<Duration>,
+    pub id: u64,
+    pub idle: Duration,
+}
+
+// On fallible derives the functions must return a `Result`
+fn try_duration_to_millis(value: Duration) -> Result<u64, std::num::TryFromIntError> {
+    u64::try_from(value.as_millis())
+}
+
+fn main() {
+    let foo = Foo {
+        timeout: 1500,
+        retry_delay: Some(250),
+        backoff: vec![100, 200],
+        id: 7,
+        idle: 60,
+    };
+
+    let bar = Bar::from(foo.clone());
+    assert_eq!(bar.timeout, Duration::from_millis(1500));
+    assert_eq!(bar.retry_delay, Some(Duration::from_millis(250)));
+    assert_eq!(
+        bar.backoff,
+        vec![Duration::from_millis(100), Duration::from_millis(200)]
+    );
+    assert_eq!(bar.id, 7);
+    assert_eq!(bar.idle, Duration::from_secs(60));
+
+    let back: Foo = bar.into();
+    assert_eq!(back, foo);
+
+    let try_bar = TryBar {
+        timeout: Duration::MAX,
+        retry_delay: None,
+        backoff: vec![],
+        id: 7,
+        idle: Duration::ZERO,
+    };
+    assert!(Foo::try_from(try_bar).is_err());
+}
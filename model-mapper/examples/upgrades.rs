@@ -0,0 +1,89 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use model_mapper::Mapper;
+
+// Persisted payloads are versioned, each version mapping from the previous one
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConfigV1 {
+    pub name: String,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, ty = ConfigV1)]
+pub struct ConfigV2 {
+    pub name: String,
+    #[mapper(skip(default(value = 3)))]
+    pub retries: u32,
+}
+
+// The latest version can be upgraded from every older one, chaining the conversions between versions
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, ty = ConfigV2, upgrade_from(ConfigV2, ConfigV1, wrapper = AnyConfig))]
+pub struct ConfigV3 {
+    #[mapper(rename = name)]
+    pub title: String,
+    pub retries: u32,
+    #[mapper(skip(default))]
+    pub verbose: bool,
+}
+// impl From<ConfigV1> for ConfigV3 {
+//     fn from(from: ConfigV1) -> Self {
+//         let previous: ConfigV2 = From::from(from);
+//         <Self as From<ConfigV2>>::from(previous)
+//     }
+// }
+//
+// pub enum AnyConfig {
+//     V3(ConfigV3),
+//     V2(ConfigV2),
+//     V1(ConfigV1),
+// }
+//
+// impl AnyConfig {
+//     pub fn upgrade(self) -> ConfigV3 {
+//         match self {
+//             Self::V3(latest) => latest,
+//             Self::V2(older) => From::from(older),
+//             Self::V1(older) => From::from(older),
+//         }
+//     }
+// }
+
+// When any of the steps is fallible, the `try` flag chains `TryFrom` instead
+#[derive(Debug, PartialEq, Clone)]
+pub struct LimitsV1 {
+    pub max: i64,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(try_from, ty = LimitsV1)]
+pub struct LimitsV2 {
+    pub max: u32,
+}
+
+#[derive(Mapper, Debug, PartialEq, Clone)]
+#[mapper(from, ty = LimitsV2, upgrade_from(LimitsV2, LimitsV1, try, wrapper = AnyLimits))]
+pub struct LimitsV3 {
+    pub max: u64,
+}
+
+fn main() {
+    let v1 = ConfigV1 {
+        name: "app".to_string(),
+    };
+
+    let latest = ConfigV3::from(v1.clone());
+    assert_eq!(latest.title, "app");
+    assert_eq!(latest.retries, 3);
+    assert!(!latest.verbose);
+
+    let loaded = [AnyConfig::from(v1), AnyConfig::V3(latest.clone())];
+    for config in loaded {
+        assert_eq!(config.upgrade(), latest);
+    }
+
+    let limits = LimitsV3::try_from(LimitsV1 { max: 10 }).unwrap();
+    assert_eq!(limits.max, 10);
+    assert!(LimitsV3::try_from(LimitsV1 { max: -1 }).is_err());
+    assert!(AnyLimits::V1(LimitsV1 { max: -1 }).upgrade().is_err());
+}
@@ -0,0 +1,88 @@
+#![allow(dead_code)]
+
+use model_mapper::Mapper;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Event {
+    UserCreated { id: u64, name: String },
+    UserDeleted(u64),
+    UserBlocked { id: u64, reason: String },
+    Tick,
+}
+
+// Structs can be mapped into a variant of the other enum, and back from it failing on any other variant
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(derive(into, try_from, ty = Event, variant = UserCreated))]
+struct UserCreated {
+    id: u64,
+    #[mapper(rename = name)]
+    username: String,
+}
+
+// Tuple structs are mapped to tuple variants
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(into, try_from, ty = Event, variant = UserDeleted)]
+struct UserDeleted(u64);
+
+// And unit structs to unit variants
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(into, try_from, ty = Event, variant = Tick)]
+struct Tick;
+
+// Fields of the variant missing on self can be provided as additional fields
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(into, ty = Event, variant = UserBlocked, add(field = reason, default(value = "unknown".into())))]
+struct UserBlocked {
+    id: u64,
+}
+
+// Instead of failing, self can be populated with a default value for any other variant
+#[derive(Debug, Default, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, ty = Event, variant = UserDeleted, default)]
+struct Deleted(Option<u64>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Heartbeat;
+
+// Unit structs can also be mapped to each other
+#[derive(Debug, Clone, PartialEq, Eq, Mapper)]
+#[mapper(from, into, ty = Heartbeat)]
+struct Ping;
+
+fn main() {
+    let created = UserCreated {
+        id: 1,
+        username: "john".into(),
+    };
+    let event = Event::from(created.clone());
+    assert_eq!(
+        event,
+        Event::UserCreated {
+            id: 1,
+            name: "john".into()
+        }
+    );
+    assert_eq!(UserCreated::try_from(event).unwrap(), created);
+    assert!(UserCreated::try_from(Event::Tick).is_err());
+
+    assert_eq!(Event::from(UserDeleted(2)), Event::UserDeleted(2));
+    assert_eq!(UserDeleted::try_from(Event::UserDeleted(2)).unwrap(), UserDeleted(2));
+
+    assert_eq!(
+        Event::from(UserBlocked { id: 4 }),
+        Event::UserBlocked {
+            id: 4,
+            reason: "unknown".into()
+        }
+    );
+
+    assert_eq!(Event::from(Tick), Event::Tick);
+    assert_eq!(Tick::try_from(Event::Tick).unwrap(), Tick);
+    assert!(Tick::try_from(Event::UserDeleted(2)).is_err());
+
+    assert_eq!(Deleted::from(Event::UserDeleted(3)), Deleted(Some(3)));
+    assert_eq!(Deleted::from(Event::Tick), Deleted(None));
+
+    assert_eq!(Heartbeat::from(Ping), Heartbeat);
+    assert_eq!(Ping::from(Heartbeat), Ping);
+}
@@ -0,0 +1,113 @@
+#![allow(dead_code, clippy::disallowed_names)]
+
+use std::collections::HashMap;
+
+use model_mapper::Mapper;
+
+// Some conversions only exist in two hops: DbMoney -> Cents -> ApiMoney
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cents(i64);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DbMoney(i64);
+
+impl From<DbMoney> for Cents {
+    fn from(value: DbMoney) -> Self {
+        Cents(value.0)
+    }
+}
+
+impl From<Cents> for DbMoney {
+    fn from(value: Cents) -> Self {
+        DbMoney(value.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ApiMoney(f64);
+
+impl From<Cents> for ApiMoney {
+    fn from(value: Cents) -> Self {
+        ApiMoney(value.0 as f64 / 100.0)
+    }
+}
+
+impl From<ApiMoney> for Cents {
+    fn from(value: ApiMoney) -> Self {
+        Cents((value.0 * 100.0).round() as i64)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Foo {
+    pub total: DbMoney,
+    pub discount: Option<DbMoney>,
+    pub taxes: HashMap<String, DbMoney>,
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(from, into, ty = Foo)]
+pub struct Bar {
+    // The value is converted into the intermediate type first and then into the other one
+    #[mapper(via = Cents)]
+    pub total: ApiMoney,
+    // It can also be nested into other hints
+    #[mapper(opt(via = Cents))]
+    pub discount: Option<ApiMoney>,
+    #[mapper(map(via = Cents))]
+    pub taxes: HashMap<String, ApiMoney>,
+}
+// impl From<Foo> for Bar {
+//     fn from(Foo { total, discount, taxes }: Foo) -> Self {
+//         Self {
+//             total: Into::into(Into::<Cents>::into(total)),
+//             ..
+//         }
+//     }
+// }
+
+// When any of the steps is fallible, the `try` flag can be used on fallible derives
+#[derive(Debug, PartialEq, Clone)]
+pub struct Baz {
+    pub code: u64,
+}
+
+#[derive(Mapper, Debug, PartialEq)]
+#[mapper(try_from, try_into, ty = Baz)]
+pub struct Qux {
+    // u64 -> i64 is fallible, while i64 -> i128 is not
+    #[mapper(via(i64, try))]
+    pub code: i128,
+}
+// impl TryFrom<Baz> for Qux {
+//     type Error = anyhow::Error;
+//
+//     fn try_from(Baz { code }: Baz) -> Result<Self, Self::Error> {
+//         Ok(Self {
+//             code: TryInto::<i64>::try_into(code)
+//                 .map_err(anyhow::Error::from)
+//                 .and_then(|code| TryInto::try_into(code).map_err(anyhow::Error::from))?,
+//         })
+//     }
+// }
+
+fn main() {
+    let foo = Foo {
+        total: DbMoney(1050),
+        discount: Some(DbMoney(50)),
+        taxes: HashMap::from([("vat".to_string(), DbMoney(21))]),
+    };
+
+    let bar = Bar::from(foo.clone());
+    assert_eq!(bar.total, ApiMoney(10.5));
+    assert_eq!(bar.discount, Some(ApiMoney(0.5)));
+    assert_eq!(bar.taxes.get("vat"), Some(&ApiMoney(0.21)));
+
+    let back: Foo = bar.into();
+    assert_eq!(back, foo);
+
+    let qux = Qux::try_from(Baz { code: 42 }).unwrap();
+    assert_eq!(qux.code, 42);
+    assert!(Qux::try_from(Baz { code: u64::MAX }).is_err());
+    assert!(Baz::try_from(Qux { code: -1 }).is_err());
+}
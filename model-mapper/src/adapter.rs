@@ -0,0 +1,34 @@
+/// A reusable conversion policy between two types, to be used with the `via_adapter` field attribute.
+///
+/// The same adapter can implement the trait for both directions, so it can be used on `from` and `into` derives.
+///
+/// ```rust
+/// # use model_mapper::Adapter;
+/// pub struct UnixMillis;
+///
+/// impl Adapter<std::time::Duration, u64> for UnixMillis {
+///     fn adapt(value: std::time::Duration) -> u64 {
+///         value.as_millis() as u64
+///     }
+/// }
+///
+/// impl Adapter<u64, std::time::Duration> for UnixMillis {
+///     fn adapt(value: u64) -> std::time::Duration {
+///         std::time::Duration::from_millis(value)
+///     }
+/// }
+/// ```
+pub trait Adapter<Src, Dst> {
+    /// Adapts the source value into the destination type
+    fn adapt(value: Src) -> Dst;
+}
+
+/// A reusable fallible conversion policy between two types, to be used with the `via_adapter` field attribute on
+/// `try_from` and `try_into` derives.
+pub trait TryAdapter<Src, Dst> {
+    /// The type returned in the event of a conversion error
+    type Error;
+
+    /// Tries to adapt the source value into the destination type
+    fn try_adapt(value: Src) -> Result<Dst, Self::Error>;
+}
@@ -0,0 +1,41 @@
+/// A bidirectional conversion between two types, to be used with the `codec` field attribute.
+///
+/// The `encode` function maps self values into the other type (used by `into` derives) while `decode` maps them
+/// back (used by `from` derives).
+///
+/// A module providing `encode` and `decode` functions can be used as a codec as well.
+///
+/// ```rust
+/// # use model_mapper::Codec;
+/// pub struct Cents;
+///
+/// impl Codec<f64, i64> for Cents {
+///     fn encode(value: f64) -> i64 {
+///         (value * 100.0).round() as i64
+///     }
+///
+///     fn decode(value: i64) -> f64 {
+///         value as f64 / 100.0
+///     }
+/// }
+/// ```
+pub trait Codec<A, B> {
+    /// Encodes a value into the other type
+    fn encode(value: A) -> B;
+    /// Decodes a value from the other type
+    fn decode(value: B) -> A;
+}
+
+/// A fallible bidirectional conversion between two types, to be used with the `codec` field attribute on `try_from`
+/// and `try_into` derives.
+///
+/// A module providing `try_encode` and `try_decode` functions can be used as a codec as well.
+pub trait TryCodec<A, B> {
+    /// The type returned in the event of a conversion error
+    type Error;
+
+    /// Tries to encode a value into the other type
+    fn try_encode(value: A) -> Result<B, Self::Error>;
+    /// Tries to decode a value from the other type
+    fn try_decode(value: B) -> Result<A, Self::Error>;
+}
@@ -0,0 +1,40 @@
+use core::fmt;
+
+/// The error returned when parsing an enum with a `str` or `int` derive from a value not matching any of its variants.
+///
+/// ```rust
+/// # use model_mapper::{Mapper, ParseEnumError};
+/// #[derive(Debug, Mapper)]
+/// #[mapper(derive(str))]
+/// enum Color {
+///     Red,
+///     Green,
+/// }
+///
+/// let err = "Blue".parse::<Color>().unwrap_err();
+/// assert_eq!(err, ParseEnumError::new("Color"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseEnumError {
+    ty: &'static str,
+}
+
+impl ParseEnumError {
+    /// Builds a new error for the given enum name
+    pub const fn new(ty: &'static str) -> Self {
+        Self { ty }
+    }
+
+    /// The name of the enum that couldn't be parsed
+    pub const fn ty(&self) -> &'static str {
+        self.ty
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the value doesn't match any variant of {}", self.ty)
+    }
+}
+
+impl core::error::Error for ParseEnumError {}
@@ -0,0 +1,174 @@
+/// A container whose inner values can be mapped while preserving its shape, used by the `inner`, `opt` and `boxed`
+/// field hints.
+///
+/// It's implemented for the standard containers, but it can be implemented for any other container as well:
+///
+/// ```rust
+/// # use model_mapper::MapInner;
+/// pub struct NonEmpty<T> {
+///     pub head: T,
+///     pub tail: Vec<T>,
+/// }
+///
+/// impl<T> MapInner for NonEmpty<T> {
+///     type Inner = T;
+///     type Output<U> = NonEmpty<U>;
+///
+///     fn map_inner<U, F: FnMut(T) -> U>(self, mut f: F) -> NonEmpty<U> {
+///         NonEmpty {
+///             head: f(self.head),
+///             tail: self.tail.into_iter().map(f).collect(),
+///         }
+///     }
+///
+///     fn try_map_inner<U, E, F: FnMut(T) -> Result<U, E>>(
+///         self,
+///         mut f: F,
+///     ) -> Result<NonEmpty<U>, E> {
+///         Ok(NonEmpty {
+///             head: f(self.head)?,
+///             tail: self.tail.into_iter().map(f).collect::<Result<_, _>>()?,
+///         })
+///     }
+/// }
+/// ```
+pub trait MapInner {
+    /// The inner value type
+    type Inner;
+    /// The same container with a different inner value type
+    type Output<U>;
+
+    /// Maps every inner value of the container
+    fn map_inner<U, F: FnMut(Self::Inner) -> U>(self, f: F) -> Self::Output<U>;
+    /// Tries to map every inner value of the container, failing on the first error
+    fn try_map_inner<U, E, F: FnMut(Self::Inner) -> Result<U, E>>(self, f: F) -> Result<Self::Output<U>, E>;
+}
+
+/// An iterator whose items can be mapped and collected into any other collection, used by the `iter` field hint.
+///
+/// It's implemented for every [IntoIterator].
+pub trait CollectInner: IntoIterator + Sized {
+    /// Maps every item and collects them
+    #[inline]
+    fn collect_inner<U, C: FromIterator<U>, F: FnMut(Self::Item) -> U>(self, f: F) -> C {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Tries to map every item and collects them, failing on the first error
+    #[inline]
+    fn try_collect_inner<U, E, C: FromIterator<U>, F: FnMut(Self::Item) -> Result<U, E>>(self, f: F) -> Result<C, E> {
+        self.into_iter().map(f).collect()
+    }
+}
+impl<I: IntoIterator> CollectInner for I {}
+
+/// A key-value iterator whose values can be mapped and collected into any other collection, used by the `map` field
+/// hint.
+///
+/// It's implemented for every [IntoIterator] of tuples.
+pub trait CollectValues<K, V>: IntoIterator<Item = (K, V)> + Sized {
+    /// Maps every value and collects them along with their keys
+    #[inline]
+    fn collect_values<U, C: FromIterator<(K, U)>, F: FnMut(V) -> U>(self, mut f: F) -> C {
+        self.into_iter().map(|(k, v)| (k, f(v))).collect()
+    }
+
+    /// Tries to map every value and collects them along with their keys, failing on the first error
+    #[inline]
+    fn try_collect_values<U, E, C: FromIterator<(K, U)>, F: FnMut(V) -> Result<U, E>>(self, mut f: F) -> Result<C, E> {
+        self.into_iter().map(|(k, v)| f(v).map(|v| (k, v))).collect()
+    }
+}
+impl<K, V, I: IntoIterator<Item = (K, V)>> CollectValues<K, V> for I {}
+
+impl<T> MapInner for Option<T> {
+    type Inner = T;
+    type Output<U> = Option<U>;
+
+    #[inline]
+    fn map_inner<U, F: FnMut(T) -> U>(self, f: F) -> Option<U> {
+        self.map(f)
+    }
+
+    #[inline]
+    fn try_map_inner<U, E, F: FnMut(T) -> Result<U, E>>(self, f: F) -> Result<Option<U>, E> {
+        self.map(f).transpose()
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use std::{
+        collections::{BTreeMap, HashMap, LinkedList, VecDeque},
+        hash::{BuildHasher, Hash},
+    };
+
+    use super::MapInner;
+
+    impl<T> MapInner for Box<T> {
+        type Inner = T;
+        type Output<U> = Box<U>;
+
+        #[inline]
+        fn map_inner<U, F: FnMut(T) -> U>(self, mut f: F) -> Box<U> {
+            Box::new(f(*self))
+        }
+
+        #[inline]
+        fn try_map_inner<U, E, F: FnMut(T) -> Result<U, E>>(self, mut f: F) -> Result<Box<U>, E> {
+            f(*self).map(Box::new)
+        }
+    }
+
+    macro_rules! impl_map_inner_seq {
+        ($($ty:ident),*) => {
+            $(
+                impl<T> MapInner for $ty<T> {
+                    type Inner = T;
+                    type Output<U> = $ty<U>;
+
+                    #[inline]
+                    fn map_inner<U, F: FnMut(T) -> U>(self, f: F) -> $ty<U> {
+                        self.into_iter().map(f).collect()
+                    }
+
+                    #[inline]
+                    fn try_map_inner<U, E, F: FnMut(T) -> Result<U, E>>(self, f: F) -> Result<$ty<U>, E> {
+                        self.into_iter().map(f).collect()
+                    }
+                }
+            )*
+        };
+    }
+    impl_map_inner_seq!(Vec, VecDeque, LinkedList);
+
+    impl<K: Eq + Hash, V, S: BuildHasher + Default> MapInner for HashMap<K, V, S> {
+        type Inner = V;
+        type Output<U> = HashMap<K, U, S>;
+
+        #[inline]
+        fn map_inner<U, F: FnMut(V) -> U>(self, mut f: F) -> HashMap<K, U, S> {
+            self.into_iter().map(|(k, v)| (k, f(v))).collect()
+        }
+
+        #[inline]
+        fn try_map_inner<U, E, F: FnMut(V) -> Result<U, E>>(self, mut f: F) -> Result<HashMap<K, U, S>, E> {
+            self.into_iter().map(|(k, v)| f(v).map(|v| (k, v))).collect()
+        }
+    }
+
+    impl<K: Ord, V> MapInner for BTreeMap<K, V> {
+        type Inner = V;
+        type Output<U> = BTreeMap<K, U>;
+
+        #[inline]
+        fn map_inner<U, F: FnMut(V) -> U>(self, mut f: F) -> BTreeMap<K, U> {
+            self.into_iter().map(|(k, v)| (k, f(v))).collect()
+        }
+
+        #[inline]
+        fn try_map_inner<U, E, F: FnMut(V) -> Result<U, E>>(self, mut f: F) -> Result<BTreeMap<K, U>, E> {
+            self.into_iter().map(|(k, v)| f(v).map(|v| (k, v))).collect()
+        }
+    }
+}